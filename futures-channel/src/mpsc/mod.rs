@@ -670,6 +670,10 @@ impl<T> Sender<T> {
     }
 
     /// Disconnects this sender from the channel, closing it if there are no more senders left.
+    ///
+    /// This has the same effect on the channel as dropping the sender, but
+    /// keeps the value around. Any message sent through a disconnected sender
+    /// is rejected as disconnected.
     pub fn disconnect(&mut self) {
         self.0 = None;
     }
@@ -700,6 +704,10 @@ impl<T> UnboundedSender<T> {
     }
 
     /// Disconnects this sender from the channel, closing it if there are no more senders left.
+    ///
+    /// This has the same effect on the channel as dropping the sender, but
+    /// keeps the value around. Any message sent through a disconnected sender
+    /// is rejected as disconnected.
     pub fn disconnect(&mut self) {
         self.0 = None;
     }
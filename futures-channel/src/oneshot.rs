@@ -332,6 +332,9 @@ impl<T> Sender<T> {
     /// then `Ok(())` is returned. If the receiving end was dropped before
     /// this function was called, however, then `Err` is returned with the value
     /// provided.
+    ///
+    /// If the value is expensive to compute, [`is_canceled`](Sender::is_canceled)
+    /// can be used to check whether anybody is still interested in it first.
     pub fn send(self, t: T) -> Result<(), T> {
         self.inner.send(t)
     }
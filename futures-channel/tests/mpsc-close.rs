@@ -65,6 +65,44 @@ fn multiple_senders_disconnect() {
     }
 }
 
+#[test]
+fn all_senders_disconnect() {
+    {
+        let (mut tx1, mut rx) = mpsc::channel(2);
+        let mut tx2 = tx1.clone();
+
+        block_on(tx1.send(1)).unwrap();
+        tx1.disconnect();
+        assert!(tx1.try_send(2).unwrap_err().is_disconnected());
+
+        // The receiver keeps getting items from the remaining sender.
+        assert_eq!(block_on(rx.next()), Some(1));
+        block_on(tx2.send(3)).unwrap();
+        assert_eq!(block_on(rx.next()), Some(3));
+
+        // Disconnecting the last sender ends the stream, even though the
+        // senders haven't been dropped.
+        tx2.disconnect();
+        assert_eq!(block_on(rx.next()), None);
+    }
+
+    {
+        let (mut tx1, mut rx) = mpsc::unbounded();
+        let mut tx2 = tx1.clone();
+
+        tx1.unbounded_send(1).unwrap();
+        tx1.disconnect();
+        assert!(tx1.unbounded_send(2).unwrap_err().is_disconnected());
+
+        assert_eq!(block_on(rx.next()), Some(1));
+        tx2.unbounded_send(3).unwrap();
+        assert_eq!(block_on(rx.next()), Some(3));
+
+        tx2.disconnect();
+        assert_eq!(block_on(rx.next()), None);
+    }
+}
+
 #[test]
 fn multiple_senders_close_channel() {
     {
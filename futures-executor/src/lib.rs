@@ -11,7 +11,12 @@
 #[cfg(feature = "std")]
 mod local_pool;
 #[cfg(feature = "std")]
-pub use crate::local_pool::{block_on, block_on_stream, BlockingStream, LocalPool, LocalSpawner};
+pub use crate::local_pool::{block_on, block_on_with, block_on_stream, BlockingStream, LocalPool, LocalSpawner};
+
+#[cfg(feature = "std")]
+mod park;
+#[cfg(feature = "std")]
+pub use crate::park::{Park, UnparkToken, StdParker};
 
 #[cfg(feature = "std")]
 mod unpark_mutex;
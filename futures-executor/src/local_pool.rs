@@ -1,11 +1,12 @@
-use crate::{enter, ThreadPool};
+use crate::{enter, Park, StdParker, ThreadPool};
+use crate::park::CURRENT_THREAD_NOTIFY;
 use futures_core::future::{Future, FutureObj, LocalFutureObj};
 use futures_core::stream::{Stream};
 use futures_core::task::{
     Poll, Waker,
     Spawn, LocalSpawn, SpawnError,
 };
-use futures_util::task::{WakerRef, waker_ref, ArcWake};
+use futures_util::task::{WakerRef, waker_ref};
 use futures_util::stream::FuturesUnordered;
 use futures_util::stream::StreamExt;
 use lazy_static::lazy_static;
@@ -14,8 +15,7 @@ use std::cell::{RefCell};
 use std::ops::{Deref, DerefMut};
 use std::prelude::v1::*;
 use std::rc::{Rc, Weak};
-use std::sync::Arc;
-use std::thread::{self, Thread};
+use std::thread;
 
 /// A single-threaded task pool for polling futures to completion.
 ///
@@ -43,22 +43,6 @@ pub struct LocalSpawner {
 
 type Incoming = RefCell<Vec<LocalFutureObj<'static, ()>>>;
 
-pub(crate) struct ThreadNotify {
-    thread: Thread
-}
-
-thread_local! {
-    static CURRENT_THREAD_NOTIFY: Arc<ThreadNotify> = Arc::new(ThreadNotify {
-        thread: thread::current(),
-    });
-}
-
-impl ArcWake for ThreadNotify {
-    fn wake(arc_self: &Arc<Self>) {
-        arc_self.thread.unpark();
-    }
-}
-
 // Set up and run a basic single-threaded spawner loop, invoking `f` on each
 // turn.
 fn run_executor<T, F: FnMut(&Waker) -> Poll<T>>(mut f: F) -> T {
@@ -206,9 +190,34 @@ lazy_static! {
 ///
 /// Use a [`LocalPool`](LocalPool) if you need finer-grained control over
 /// spawned tasks.
+///
+/// The future is taken by value and pinned on the stack of this function, so
+/// it doesn't need to implement `Unpin`: `async` blocks can be passed
+/// directly.
 pub fn block_on<F: Future>(f: F) -> F::Output {
+    block_on_with(f, StdParker)
+}
+
+/// Run a future to completion on the current thread, using `parker` to block
+/// while the future is pending.
+///
+/// This works like [`block_on`](block_on), but instead of `std::thread`
+/// parking, the thread is blocked with [`Park::park`](Park::park) and the
+/// future's waker unparks it through the parker's
+/// [`UnparkToken`](crate::UnparkToken).
+pub fn block_on_with<F: Future, P: Park>(f: F, parker: P) -> F::Output {
+    let _enter = enter()
+        .expect("cannot execute `block_on` executor from within \
+                 another executor");
+
     pin_mut!(f);
-    run_executor(|waker| f.as_mut().poll(waker))
+    let token = parker.unpark_token();
+    loop {
+        if let Poll::Ready(t) = f.as_mut().poll(&token.waker) {
+            return t;
+        }
+        parker.park();
+    }
 }
 
 /// Turn a stream into a blocking iterator.
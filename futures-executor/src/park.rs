@@ -0,0 +1,89 @@
+use futures_core::task::Waker;
+use futures_util::task::ArcWake;
+use std::fmt;
+use std::sync::Arc;
+use std::thread::{self, Thread};
+
+/// A way of blocking the current thread until it is unparked.
+///
+/// This is the primitive used by [`block_on_with`](crate::block_on_with) to
+/// wait for a future to be woken up. Implementing it allows `block_on_with`
+/// to be used with threading models other than `std::thread`, or with
+/// deterministic parkers in tests.
+pub trait Park {
+    /// Blocks the current thread until the token returned by
+    /// [`unpark_token`](Park::unpark_token) is used to unpark it.
+    ///
+    /// Implementations may return spuriously, as the future will simply be
+    /// polled again.
+    fn park(&self);
+
+    /// Returns a token which can be used to unpark this parker, possibly from
+    /// another thread.
+    fn unpark_token(&self) -> UnparkToken;
+}
+
+/// A handle used to unpark a [`Park`] implementation.
+///
+/// The token holds the [`Waker`](futures_core::task::Waker) that
+/// `block_on_with` hands to the future, so waking the future unparks the
+/// parker.
+#[derive(Clone)]
+pub struct UnparkToken {
+    pub(crate) waker: Waker,
+}
+
+impl UnparkToken {
+    /// Creates a new token which unparks through a shared [`ArcWake`]
+    /// implementation.
+    ///
+    /// Waking it must unblock the thread parked by the associated [`Park`],
+    /// or make its next call to [`park`](Park::park) return immediately if it
+    /// isn't parked yet.
+    pub fn new<W: ArcWake + Send + Sync + 'static>(unpark: Arc<W>) -> UnparkToken {
+        UnparkToken { waker: ArcWake::into_waker(unpark) }
+    }
+
+    /// Unparks the associated parker.
+    pub fn unpark(&self) {
+        self.waker.wake()
+    }
+}
+
+impl fmt::Debug for UnparkToken {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "UnparkToken {{ ... }}")
+    }
+}
+
+/// A [`Park`] implementation using `std::thread` parking.
+///
+/// This is the parker used by [`block_on`](crate::block_on).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StdParker;
+
+pub(crate) struct ThreadNotify {
+    thread: Thread
+}
+
+thread_local! {
+    pub(crate) static CURRENT_THREAD_NOTIFY: Arc<ThreadNotify> = Arc::new(ThreadNotify {
+        thread: thread::current(),
+    });
+}
+
+impl ArcWake for ThreadNotify {
+    fn wake(arc_self: &Arc<Self>) {
+        arc_self.thread.unpark();
+    }
+}
+
+impl Park for StdParker {
+    fn park(&self) {
+        thread::park();
+    }
+
+    fn unpark_token(&self) -> UnparkToken {
+        CURRENT_THREAD_NOTIFY.with(|notify| UnparkToken::new(notify.clone()))
+    }
+}
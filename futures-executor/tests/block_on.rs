@@ -0,0 +1,45 @@
+#![feature(async_await, await_macro, futures_api)]
+
+use futures::executor::block_on;
+use futures::future::{self, Future};
+use futures::task::{Waker, Poll};
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+
+struct NotUnpin {
+    polled: bool,
+    _pinned: PhantomPinned,
+}
+
+impl Future for NotUnpin {
+    type Output = u32;
+
+    fn poll(self: Pin<&mut Self>, waker: &Waker) -> Poll<u32> {
+        // Safe because `polled` isn't structurally pinned.
+        let this = unsafe { Pin::get_unchecked_mut(self) };
+        if this.polled {
+            Poll::Ready(7)
+        } else {
+            this.polled = true;
+            waker.wake();
+            Poll::Pending
+        }
+    }
+}
+
+#[test]
+fn block_on_not_unpin_future() {
+    let fut = NotUnpin { polled: false, _pinned: PhantomPinned };
+    assert_eq!(block_on(fut), 7);
+}
+
+#[test]
+fn block_on_async_block_holding_borrow() {
+    let data = vec![1, 2, 3];
+    let sum = block_on(async {
+        let slice = &data[..];
+        await!(future::ready(()));
+        slice.iter().sum::<i32>()
+    });
+    assert_eq!(sum, 6);
+}
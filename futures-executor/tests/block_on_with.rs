@@ -0,0 +1,66 @@
+#![feature(futures_api)]
+
+use futures::executor::{block_on_with, Park, UnparkToken};
+use futures::future::{self, Future};
+use futures::task::{ArcWake, Waker, Poll};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Default)]
+struct Counts {
+    parks: AtomicUsize,
+    unparks: AtomicUsize,
+}
+
+impl ArcWake for Counts {
+    fn wake(arc_self: &Arc<Self>) {
+        arc_self.unparks.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+struct MockParker(Arc<Counts>);
+
+impl Park for MockParker {
+    fn park(&self) {
+        // Every park must have been preceded by an unpark, otherwise a real
+        // parker would block forever.
+        let parks = self.0.parks.fetch_add(1, Ordering::SeqCst) + 1;
+        assert!(parks <= self.0.unparks.load(Ordering::SeqCst));
+    }
+
+    fn unpark_token(&self) -> UnparkToken {
+        UnparkToken::new(self.0.clone())
+    }
+}
+
+struct YieldNow(usize);
+
+impl Future for YieldNow {
+    type Output = usize;
+
+    fn poll(mut self: Pin<&mut Self>, waker: &Waker) -> Poll<usize> {
+        if self.0 == 0 {
+            return Poll::Ready(42);
+        }
+        self.0 -= 1;
+        waker.wake();
+        Poll::Pending
+    }
+}
+
+#[test]
+fn ready_future_never_parks() {
+    let counts = Arc::new(Counts::default());
+    assert_eq!(block_on_with(future::ready(1), MockParker(counts.clone())), 1);
+    assert_eq!(counts.parks.load(Ordering::SeqCst), 0);
+    assert_eq!(counts.unparks.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn parks_once_per_wake() {
+    let counts = Arc::new(Counts::default());
+    assert_eq!(block_on_with(YieldNow(3), MockParker(counts.clone())), 42);
+    assert_eq!(counts.parks.load(Ordering::SeqCst), 3);
+    assert_eq!(counts.unparks.load(Ordering::SeqCst), 3);
+}
@@ -1,7 +1,7 @@
 //! Asynchronous I/O
 //!
-//! This crate contains the `AsyncRead` and `AsyncWrite` traits, the
-//! asynchronous analogs to `std::io::{Read, Write}`. The primary difference is
+//! This crate contains the `AsyncRead`, `AsyncBufRead` and `AsyncWrite`
+//! traits, the asynchronous analogs to `std::io::{Read, BufRead, Write}`. The primary difference is
 //! that these traits integrate with the asynchronous task system.
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -145,6 +145,46 @@ mod if_std {
         }
     }
 
+    /// Read bytes asynchronously from a buffered source.
+    ///
+    /// This trait is analogous to the `std::io::BufRead` trait, but integrates
+    /// with the asynchronous task system. In particular, the `poll_fill_buf`
+    /// method, unlike `BufRead::fill_buf`, will automatically queue the current
+    /// task for wakeup and return if data is not yet available, rather than
+    /// blocking the calling thread.
+    pub trait AsyncBufRead: AsyncRead {
+        /// Attempt to return the contents of the internal buffer, filling it
+        /// with more data from the inner reader if it is empty.
+        ///
+        /// On success, returns `Ok(Async::Ready(buf))`. An empty buffer
+        /// indicates that the underlying reader has reached EOF.
+        ///
+        /// If no data is available for reading, the method returns
+        /// `Ok(Async::Pending)` and arranges for the current task (via
+        /// `waker.wake()`) to receive a notification when the object becomes
+        /// readable or is closed.
+        ///
+        /// This function is a lower-level call. It needs to be paired with the
+        /// [`consume`](AsyncBufRead::consume) method to function properly.
+        ///
+        /// # Implementation
+        ///
+        /// This function may not return errors of kind `WouldBlock` or
+        /// `Interrupted`.  Implementations must convert `WouldBlock` into
+        /// `Async::Pending` and either internally retry or convert
+        /// `Interrupted` into another error kind.
+        fn poll_fill_buf<'a>(&'a mut self, waker: &Waker)
+            -> Poll<Result<&'a [u8]>>;
+
+        /// Tells this buffer that `amt` bytes have been consumed from the
+        /// buffer, so they should no longer be returned in calls to
+        /// [`poll_fill_buf`](AsyncBufRead::poll_fill_buf).
+        ///
+        /// The `amt` must be `<=` the number of bytes in the buffer returned
+        /// by `poll_fill_buf`.
+        fn consume(&mut self, amt: usize);
+    }
+
     /// Write bytes asynchronously.
     ///
     /// This trait is analogous to the `std::io::Write` trait, but integrates
@@ -293,10 +333,62 @@ mod if_std {
         unsafe_delegate_async_read_to_stdio!();
     }
 
+    impl AsyncRead for StdIo::Empty {
+        unsafe_delegate_async_read_to_stdio!();
+    }
+
     impl<T: AsRef<[u8]>> AsyncRead for StdIo::Cursor<T> {
         unsafe_delegate_async_read_to_stdio!();
     }
 
+    macro_rules! deref_async_buf_read {
+        () => {
+            fn poll_fill_buf<'a>(&'a mut self, waker: &Waker)
+                -> Poll<Result<&'a [u8]>>
+            {
+                (**self).poll_fill_buf(waker)
+            }
+
+            fn consume(&mut self, amt: usize) {
+                (**self).consume(amt)
+            }
+        }
+    }
+
+    impl<T: ?Sized + AsyncBufRead> AsyncBufRead for Box<T> {
+        deref_async_buf_read!();
+    }
+
+    impl<'a, T: ?Sized + AsyncBufRead> AsyncBufRead for &'a mut T {
+        deref_async_buf_read!();
+    }
+
+    macro_rules! delegate_async_buf_read_to_stdio {
+        () => {
+            fn poll_fill_buf<'a>(&'a mut self, _: &Waker)
+                -> Poll<Result<&'a [u8]>>
+            {
+                Poll::Ready(StdIo::BufRead::fill_buf(self))
+            }
+
+            fn consume(&mut self, amt: usize) {
+                StdIo::BufRead::consume(self, amt)
+            }
+        }
+    }
+
+    impl<'a> AsyncBufRead for &'a [u8] {
+        delegate_async_buf_read_to_stdio!();
+    }
+
+    impl<T: AsRef<[u8]>> AsyncBufRead for StdIo::Cursor<T> {
+        delegate_async_buf_read_to_stdio!();
+    }
+
+    impl AsyncBufRead for StdIo::Empty {
+        delegate_async_buf_read_to_stdio!();
+    }
+
     macro_rules! deref_async_write {
         () => {
             fn poll_write(&mut self, waker: &Waker, buf: &[u8])
@@ -1,8 +1,12 @@
+use futures_core::future::Future;
 use futures_core::stream::Stream;
 
 #[doc(hidden)]
 pub fn assert_is_unpin_stream<S: Stream + Unpin>(_: &mut S) {}
 
+#[doc(hidden)]
+pub fn assert_is_unpin_future<F: Future + Unpin>(_: &mut F) {}
+
 /// Assert that the next poll to the provided stream will return
 /// [`Poll::Pending`](futures_core::task::Poll::Pending).
 ///
@@ -122,3 +126,158 @@ macro_rules! assert_stream_done {
         }
     }}
 }
+
+/// Assert that the next poll to the provided future will return
+/// [`Poll::Pending`](futures_core::task::Poll::Pending).
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await, futures_api)]
+/// use futures::future;
+/// use futures_test::future::FutureTestExt;
+/// use futures_test::{assert_pending, assert_ready_eq};
+///
+/// let mut future = future::ready(5).pending_once();
+///
+/// assert_pending!(future);
+/// assert_ready_eq!(future, 5);
+/// ```
+#[macro_export]
+macro_rules! assert_pending {
+    ($future:expr) => {{
+        let mut future = &mut $future;
+        $crate::assert::assert_is_unpin_future(future);
+        let future = $crate::std_reexport::pin::Pin::new(future);
+        let lw = &$crate::task::noop_waker_ref();
+        let poll = $crate::futures_core_reexport::future::Future::poll(
+            future, lw,
+        );
+        if poll.is_ready() {
+            panic!("assertion failed: future is not pending");
+        }
+    }};
+}
+
+/// Assert that the next poll to the provided future will return
+/// [`Poll::Ready`](futures_core::task::Poll::Ready), evaluating to the
+/// output of the future.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await, futures_api)]
+/// use futures::future;
+/// use futures_test::future::FutureTestExt;
+/// use futures_test::{assert_pending, assert_ready};
+///
+/// let mut future = future::ready(5).pending_once();
+///
+/// assert_pending!(future);
+/// let output = assert_ready!(future);
+/// assert_eq!(output, 5);
+/// ```
+#[macro_export]
+macro_rules! assert_ready {
+    ($future:expr) => {{
+        let mut future = &mut $future;
+        $crate::assert::assert_is_unpin_future(future);
+        let future = $crate::std_reexport::pin::Pin::new(future);
+        let lw = &$crate::task::noop_waker_ref();
+        match $crate::futures_core_reexport::future::Future::poll(future, lw) {
+            $crate::futures_core_reexport::task::Poll::Ready(output) => output,
+            $crate::futures_core_reexport::task::Poll::Pending => {
+                panic!("assertion failed: future is not ready");
+            }
+        }
+    }};
+}
+
+/// Assert that the next poll to the provided future will return
+/// [`Poll::Ready`](futures_core::task::Poll::Ready) with the provided
+/// output.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await, futures_api)]
+/// use futures::future;
+/// use futures_test::future::FutureTestExt;
+/// use futures_test::{assert_pending, assert_ready_eq};
+///
+/// let mut future = future::ready(5).pending_once();
+///
+/// assert_pending!(future);
+/// assert_ready_eq!(future, 5);
+/// ```
+#[macro_export]
+macro_rules! assert_ready_eq {
+    ($future:expr, $output:expr) => {{
+        let mut future = &mut $future;
+        $crate::assert::assert_is_unpin_future(future);
+        let future = $crate::std_reexport::pin::Pin::new(future);
+        let lw = &$crate::task::noop_waker_ref();
+        match $crate::futures_core_reexport::future::Future::poll(future, lw) {
+            $crate::futures_core_reexport::task::Poll::Ready(output) => {
+                assert_eq!(output, $output);
+            }
+            $crate::futures_core_reexport::task::Poll::Pending => {
+                panic!("assertion failed: expected future to be ready but it was pending");
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::future::{empty, ready};
+
+    use crate::future::FutureTestExt;
+
+    #[test]
+    fn pending() {
+        let mut future = empty::<()>();
+        assert_pending!(future);
+    }
+
+    #[test]
+    #[should_panic(expected = "future is not pending")]
+    fn pending_but_ready() {
+        let mut future = ready(1);
+        assert_pending!(future);
+    }
+
+    #[test]
+    fn ready_output() {
+        let mut future = ready(1).pending_once();
+        assert_pending!(future);
+        assert_eq!(assert_ready!(future), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "future is not ready")]
+    fn ready_but_pending() {
+        let mut future = empty::<()>();
+        assert_ready!(future);
+    }
+
+    #[test]
+    fn ready_eq() {
+        let mut future = ready(1);
+        assert_ready_eq!(future, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ready_eq_wrong_output() {
+        let mut future = ready(1);
+        assert_ready_eq!(future, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected future to be ready but it was pending")]
+    fn ready_eq_but_pending() {
+        let mut future = empty::<i32>();
+        assert_ready_eq!(future, 1);
+    }
+}
@@ -0,0 +1,84 @@
+use futures_core::future::{Future, FusedFuture};
+use futures_core::task::{Waker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use std::pin::Pin;
+
+/// Combinator that delays every [`Poll::Ready`] of its inner future by one
+/// [`Poll::Pending`].
+///
+/// This is created by the
+/// [`FutureTestExt::interleave_pending`](super::FutureTestExt::interleave_pending)
+/// method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct InterleavePending<Fut: Future> {
+    future: Fut,
+    output: Option<Fut::Output>,
+}
+
+impl<Fut: Future> InterleavePending<Fut> {
+    unsafe_pinned!(future: Fut);
+    unsafe_unpinned!(output: Option<Fut::Output>);
+
+    pub(super) fn new(future: Fut) -> Self {
+        Self {
+            future,
+            output: None,
+        }
+    }
+}
+
+impl<Fut: Future> Future for InterleavePending<Fut> {
+    type Output = Fut::Output;
+
+    fn poll(
+        mut self: Pin<&mut Self>,
+        waker: &Waker,
+    ) -> Poll<Self::Output> {
+        if let Some(output) = self.as_mut().output().take() {
+            return Poll::Ready(output);
+        }
+        match self.as_mut().future().poll(waker) {
+            Poll::Ready(output) => {
+                *self.as_mut().output() = Some(output);
+                waker.wake();
+                Poll::Pending
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<Fut: Future + FusedFuture> FusedFuture for InterleavePending<Fut> {
+    fn is_terminated(&self) -> bool {
+        self.output.is_none() && self.future.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_core::task::Poll;
+    use futures_executor::block_on;
+    use futures_util::future::{ready, FutureExt};
+    use crate::task::new_count_waker;
+
+    use super::InterleavePending;
+
+    #[test]
+    fn delays_ready_once() {
+        let (waker, count) = new_count_waker();
+        let mut future = InterleavePending::new(ready(1));
+
+        assert_eq!(future.poll_unpin(&waker), Poll::Pending);
+        assert_eq!(count, 1);
+        assert_eq!(future.poll_unpin(&waker), Poll::Ready(1));
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn runs_to_completion() {
+        let future = InterleavePending::new(ready(1))
+            .join(InterleavePending::new(ready(2)));
+        assert_eq!(block_on(future), (1, 2));
+    }
+}
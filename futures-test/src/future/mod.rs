@@ -3,13 +3,37 @@
 mod assert_unmoved;
 pub use self::assert_unmoved::AssertUnmoved;
 
+mod interleave_pending;
+pub use self::interleave_pending::InterleavePending;
+
 mod pending_once;
 pub use self::pending_once::PendingOnce;
 
 use futures_core::future::Future;
+use futures_core::task::Poll;
 use futures_executor;
+use std::pin::Pin;
 use std::thread;
 
+/// Polls the given future once with a no-op waker, returning the result.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(futures_api)]
+/// use futures::future;
+/// use futures::task::Poll;
+/// use futures_test::future::{poll_once, FutureTestExt};
+///
+/// let mut future = future::ready(5).pending_once();
+///
+/// assert_eq!(poll_once(&mut future), Poll::Pending);
+/// assert_eq!(poll_once(&mut future), Poll::Ready(5));
+/// ```
+pub fn poll_once<Fut: Future + Unpin>(future: &mut Fut) -> Poll<Fut::Output> {
+    Pin::new(future).poll(crate::task::noop_waker_ref())
+}
+
 /// Additional combinators for testing futures.
 pub trait FutureTestExt: Future {
     /// Asserts that the given is not moved after being polled.
@@ -55,6 +79,41 @@ pub trait FutureTestExt: Future {
         pending_once::PendingOnce::new(self)
     }
 
+    /// Introduces one [`Poll::Pending`](futures_core::task::Poll::Pending)
+    /// before every [`Poll::Ready`](futures_core::task::Poll::Ready) of the
+    /// given future.
+    ///
+    /// When the inner future completes, its output is held back and the task
+    /// is woken immediately; the output is only returned by the next poll.
+    /// This is more aggressive than
+    /// [`pending_once`](FutureTestExt::pending_once) and helps to surface
+    /// combinators which don't poll their inner futures again correctly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, futures_api)]
+    /// use futures::task::Poll;
+    /// use futures::future::FutureExt;
+    /// use futures_test::task;
+    /// use futures_test::future::FutureTestExt;
+    /// use pin_utils::pin_mut;
+    ///
+    /// let future = (async { 5 }).interleave_pending();
+    /// pin_mut!(future);
+    ///
+    /// let lw = &task::noop_waker_ref();
+    ///
+    /// assert_eq!(future.poll_unpin(lw), Poll::Pending);
+    /// assert_eq!(future.poll_unpin(lw), Poll::Ready(5));
+    /// ```
+    fn interleave_pending(self) -> InterleavePending<Self>
+    where
+        Self: Sized,
+    {
+        InterleavePending::new(self)
+    }
+
     /// Runs this future on a dedicated executor running in a background thread.
     ///
     /// # Examples
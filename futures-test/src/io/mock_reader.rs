@@ -0,0 +1,86 @@
+use futures_core::task::{Waker, Poll};
+use futures_io::AsyncRead;
+use std::collections::VecDeque;
+use std::io;
+
+/// An [`AsyncRead`] which returns a scripted sequence of results.
+///
+/// Each step of the script is consumed by a call to `poll_read`:
+///
+/// - `Poll::Pending` wakes the task immediately and returns `Pending`.
+/// - `Poll::Ready(Ok(data))` reads as much of `data` as fits into the buffer.
+///   Whatever doesn't fit is returned by the following calls before the next
+///   step of the script is used.
+/// - `Poll::Ready(Err(e))` returns the error.
+///
+/// Once the script is exhausted, the reader is at EOF.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(futures_api)]
+/// use futures::io::AsyncRead;
+/// use futures::task::Poll;
+/// use futures_test::io::MockReader;
+/// use futures_test::task::noop_waker_ref;
+///
+/// let mut reader = MockReader::new(vec![
+///     Poll::Pending,
+///     Poll::Ready(Ok(vec![1, 2, 3])),
+/// ]);
+/// let lw = noop_waker_ref();
+/// let mut buf = [0; 2];
+///
+/// assert!(reader.poll_read(lw, &mut buf).is_pending());
+/// assert_eq!(reader.poll_read(lw, &mut buf).map(Result::unwrap), Poll::Ready(2));
+/// assert_eq!(buf, [1, 2]);
+/// assert_eq!(reader.poll_read(lw, &mut buf).map(Result::unwrap), Poll::Ready(1));
+/// assert_eq!(buf[0], 3);
+/// assert_eq!(reader.poll_read(lw, &mut buf).map(Result::unwrap), Poll::Ready(0));
+/// ```
+#[derive(Debug)]
+pub struct MockReader {
+    script: VecDeque<Poll<io::Result<Vec<u8>>>>,
+    partial: Vec<u8>,
+}
+
+impl MockReader {
+    /// Creates a new reader which plays back the given script.
+    pub fn new<I>(script: I) -> Self
+    where
+        I: IntoIterator<Item = Poll<io::Result<Vec<u8>>>>,
+    {
+        Self {
+            script: script.into_iter().collect(),
+            partial: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if the whole script has been played back.
+    pub fn is_done(&self) -> bool {
+        self.script.is_empty() && self.partial.is_empty()
+    }
+}
+
+impl AsyncRead for MockReader {
+    fn poll_read(&mut self, waker: &Waker, buf: &mut [u8])
+        -> Poll<io::Result<usize>>
+    {
+        if self.partial.is_empty() {
+            match self.script.pop_front() {
+                None => return Poll::Ready(Ok(0)),
+                Some(Poll::Pending) => {
+                    waker.wake();
+                    return Poll::Pending;
+                }
+                Some(Poll::Ready(Err(e))) => return Poll::Ready(Err(e)),
+                Some(Poll::Ready(Ok(data))) => self.partial = data,
+            }
+        }
+
+        let n = buf.len().min(self.partial.len());
+        buf[..n].copy_from_slice(&self.partial[..n]);
+        self.partial.drain(..n);
+        Poll::Ready(Ok(n))
+    }
+}
@@ -0,0 +1,89 @@
+use futures_core::task::{Waker, Poll};
+use futures_io::AsyncWrite;
+use std::collections::VecDeque;
+use std::io;
+
+/// An [`AsyncWrite`] which records the written bytes and accepts writes
+/// according to a scripted sequence of results.
+///
+/// Each step of the script is consumed by a call to `poll_write`:
+///
+/// - `Poll::Pending` wakes the task immediately and returns `Pending`.
+/// - `Poll::Ready(Ok(n))` accepts at most `n` bytes of the buffer, which
+///   makes it easy to simulate short writes.
+/// - `Poll::Ready(Err(e))` returns the error.
+///
+/// Once the script is exhausted, every write is accepted in full. Flushing
+/// and closing always succeed immediately.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(futures_api)]
+/// use futures::io::AsyncWrite;
+/// use futures::task::Poll;
+/// use futures_test::io::MockWriter;
+/// use futures_test::task::noop_waker_ref;
+///
+/// let mut writer = MockWriter::new(vec![Poll::Pending, Poll::Ready(Ok(1))]);
+/// let lw = noop_waker_ref();
+///
+/// assert!(writer.poll_write(lw, &[1, 2, 3]).is_pending());
+/// assert_eq!(writer.poll_write(lw, &[1, 2, 3]).map(Result::unwrap), Poll::Ready(1));
+/// assert_eq!(writer.poll_write(lw, &[2, 3]).map(Result::unwrap), Poll::Ready(2));
+/// assert_eq!(writer.written(), &[1, 2, 3]);
+/// ```
+#[derive(Debug)]
+pub struct MockWriter {
+    script: VecDeque<Poll<io::Result<usize>>>,
+    written: Vec<u8>,
+}
+
+impl MockWriter {
+    /// Creates a new writer which plays back the given script.
+    pub fn new<I>(script: I) -> Self
+    where
+        I: IntoIterator<Item = Poll<io::Result<usize>>>,
+    {
+        Self {
+            script: script.into_iter().collect(),
+            written: Vec::new(),
+        }
+    }
+
+    /// Returns the bytes which have been written so far.
+    pub fn written(&self) -> &[u8] {
+        &self.written
+    }
+
+    /// Returns `true` if the whole script has been played back.
+    pub fn is_done(&self) -> bool {
+        self.script.is_empty()
+    }
+}
+
+impl AsyncWrite for MockWriter {
+    fn poll_write(&mut self, waker: &Waker, buf: &[u8])
+        -> Poll<io::Result<usize>>
+    {
+        let n = match self.script.pop_front() {
+            None => buf.len(),
+            Some(Poll::Pending) => {
+                waker.wake();
+                return Poll::Pending;
+            }
+            Some(Poll::Ready(Err(e))) => return Poll::Ready(Err(e)),
+            Some(Poll::Ready(Ok(n))) => n.min(buf.len()),
+        };
+        self.written.extend_from_slice(&buf[..n]);
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(&mut self, _: &Waker) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(&mut self, _: &Waker) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
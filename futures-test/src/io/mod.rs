@@ -0,0 +1,13 @@
+//! Mock I/O objects for testing `AsyncRead` and `AsyncWrite` consumers.
+//!
+//! - [`MockReader`] returns a scripted sequence of reads, including
+//!   [`Poll::Pending`](futures_core::task::Poll::Pending)s, short reads and
+//!   errors.
+//! - [`MockWriter`] records everything written to it and accepts writes
+//!   according to a scripted sequence of results.
+
+mod mock_reader;
+pub use self::mock_reader::MockReader;
+
+mod mock_writer;
+pub use self::mock_writer::MockWriter;
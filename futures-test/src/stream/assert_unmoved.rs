@@ -0,0 +1,109 @@
+use futures_core::stream::Stream;
+use futures_core::task::{Waker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::ptr;
+use std::thread::panicking;
+
+/// Combinator for the
+/// [`StreamTestExt::assert_unmoved`](super::StreamTestExt::assert_unmoved)
+/// method.
+#[derive(Debug, Clone)]
+#[must_use = "streams do nothing unless polled"]
+pub struct AssertUnmoved<St> {
+    stream: St,
+    this_ptr: *const AssertUnmoved<St>,
+    _pinned: PhantomPinned,
+}
+
+impl<St> AssertUnmoved<St> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(this_ptr: *const Self);
+
+    pub(super) fn new(stream: St) -> Self {
+        Self {
+            stream,
+            this_ptr: ptr::null(),
+            _pinned: PhantomPinned,
+        }
+    }
+}
+
+impl<St: Stream> Stream for AssertUnmoved<St> {
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        waker: &Waker,
+    ) -> Poll<Option<Self::Item>> {
+        let cur_this = &*self as *const Self;
+        if self.this_ptr.is_null() {
+            // First time being polled
+            *self.as_mut().this_ptr() = cur_this;
+        } else {
+            assert_eq!(self.this_ptr, cur_this, "Stream moved between poll calls");
+        }
+        self.as_mut().stream().poll_next(waker)
+    }
+}
+
+impl<St> Drop for AssertUnmoved<St> {
+    fn drop(&mut self) {
+        // If the thread is panicking then we can't panic again as that will
+        // cause the process to be aborted.
+        if !panicking() && !self.this_ptr.is_null() {
+            let cur_this = &*self as *const Self;
+            assert_eq!(self.this_ptr, cur_this, "Stream moved before drop");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_core::stream::Stream;
+    use futures_core::task::Poll;
+    use futures_util::stream::{empty, iter};
+    use futures_util::task::noop_waker;
+    use pin_utils::pin_mut;
+    use std::pin::Pin;
+
+    use super::AssertUnmoved;
+
+    #[test]
+    fn dont_panic_when_not_polled() {
+        // This shouldn't panic.
+        let stream = AssertUnmoved::new(empty::<()>());
+        drop(stream);
+    }
+
+    #[test]
+    fn dont_panic_when_pinned() {
+        let waker = noop_waker();
+
+        let stream = AssertUnmoved::new(iter(vec![1, 2]));
+        pin_mut!(stream);
+        assert_eq!(stream.as_mut().poll_next(&waker), Poll::Ready(Some(1)));
+        assert_eq!(stream.as_mut().poll_next(&waker), Poll::Ready(Some(2)));
+        assert_eq!(stream.as_mut().poll_next(&waker), Poll::Ready(None));
+    }
+
+    #[test]
+    #[should_panic(expected = "Stream moved between poll calls")]
+    fn dont_double_panic() {
+        // This test should only panic, not abort the process.
+        let waker = noop_waker();
+
+        // First we allocate the stream on the stack and poll it.
+        let mut stream = AssertUnmoved::new(empty::<()>());
+        let pinned_stream = unsafe { Pin::new_unchecked(&mut stream) };
+        assert_eq!(pinned_stream.poll_next(&waker), Poll::Ready(None));
+
+        // Next we move it to the heap and poll it again. This second call
+        // should panic (as the stream is moved), but we shouldn't panic again
+        // whilst dropping `AssertUnmoved`.
+        let mut stream = Box::new(stream);
+        let pinned_boxed_stream = unsafe { Pin::new_unchecked(&mut *stream) };
+        assert_eq!(pinned_boxed_stream.poll_next(&waker), Poll::Ready(None));
+    }
+}
@@ -0,0 +1,87 @@
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Waker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use std::pin::Pin;
+
+/// Stream combinator that delays every [`Poll::Ready`] of its inner stream
+/// by one [`Poll::Pending`].
+///
+/// This is created by the
+/// [`StreamTestExt::interleave_pending`](super::StreamTestExt::interleave_pending)
+/// method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct InterleavePending<St: Stream> {
+    stream: St,
+    item: Option<Option<St::Item>>,
+}
+
+impl<St: Stream> InterleavePending<St> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(item: Option<Option<St::Item>>);
+
+    pub(super) fn new(stream: St) -> Self {
+        Self {
+            stream,
+            item: None,
+        }
+    }
+}
+
+impl<St: Stream> Stream for InterleavePending<St> {
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        waker: &Waker,
+    ) -> Poll<Option<Self::Item>> {
+        if let Some(item) = self.as_mut().item().take() {
+            return Poll::Ready(item);
+        }
+        match self.as_mut().stream().poll_next(waker) {
+            Poll::Ready(item) => {
+                *self.as_mut().item() = Some(item);
+                waker.wake();
+                Poll::Pending
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<St: FusedStream> FusedStream for InterleavePending<St> {
+    fn is_terminated(&self) -> bool {
+        self.item.is_none() && self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_core::task::Poll;
+    use futures_executor::block_on;
+    use futures_util::stream::{iter, StreamExt};
+    use crate::task::new_count_waker;
+
+    use super::InterleavePending;
+
+    #[test]
+    fn delays_every_item() {
+        let (waker, count) = new_count_waker();
+        let mut stream = InterleavePending::new(iter(vec![1, 2]));
+
+        assert_eq!(stream.poll_next_unpin(&waker), Poll::Pending);
+        assert_eq!(stream.poll_next_unpin(&waker), Poll::Ready(Some(1)));
+        assert_eq!(stream.poll_next_unpin(&waker), Poll::Pending);
+        assert_eq!(stream.poll_next_unpin(&waker), Poll::Ready(Some(2)));
+        assert_eq!(stream.poll_next_unpin(&waker), Poll::Pending);
+        assert_eq!(stream.poll_next_unpin(&waker), Poll::Ready(None));
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn runs_to_completion() {
+        let stream = InterleavePending::new(iter(vec![1, 2, 3]))
+            .zip(InterleavePending::new(iter(vec![4, 5, 6])));
+        assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![(1, 4), (2, 5), (3, 6)]);
+    }
+}
@@ -0,0 +1,65 @@
+//! Additional streams and combinators for testing streams.
+
+mod assert_unmoved;
+pub use self::assert_unmoved::AssertUnmoved;
+
+mod interleave_pending;
+pub use self::interleave_pending::InterleavePending;
+
+mod once_then_pending;
+pub use self::once_then_pending::{once_then_pending, OnceThenPending};
+
+use futures_core::stream::Stream;
+
+/// Additional combinators for testing streams.
+pub trait StreamTestExt: Stream {
+    /// Asserts that the given stream is not moved after being polled.
+    ///
+    /// A check for movement is performed each time the stream is polled
+    /// and when `Drop` is called.
+    ///
+    /// Aside from keeping track of the location at which the stream was first
+    /// polled and providing assertions, this stream adds no runtime behavior
+    /// and simply delegates to the child stream.
+    fn assert_unmoved(self) -> AssertUnmoved<Self>
+    where
+        Self: Sized,
+    {
+        AssertUnmoved::new(self)
+    }
+
+    /// Introduces one [`Poll::Pending`](futures_core::task::Poll::Pending)
+    /// before every [`Poll::Ready`](futures_core::task::Poll::Ready) of the
+    /// given stream, including the final one signalling its end.
+    ///
+    /// Each ready result of the inner stream is held back and the task is
+    /// woken immediately; the result is only returned by the next poll. This
+    /// helps to surface combinators which don't poll their inner streams
+    /// again correctly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(futures_api)]
+    /// use futures::stream::{self, StreamExt};
+    /// use futures::task::Poll;
+    /// use futures_test::stream::StreamTestExt;
+    /// use futures_test::task;
+    ///
+    /// let mut stream = stream::iter(vec![1]).interleave_pending();
+    /// let lw = &task::noop_waker_ref();
+    ///
+    /// assert_eq!(stream.poll_next_unpin(lw), Poll::Pending);
+    /// assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    /// assert_eq!(stream.poll_next_unpin(lw), Poll::Pending);
+    /// assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+    /// ```
+    fn interleave_pending(self) -> InterleavePending<Self>
+    where
+        Self: Sized,
+    {
+        InterleavePending::new(self)
+    }
+}
+
+impl<St> StreamTestExt for St where St: Stream {}
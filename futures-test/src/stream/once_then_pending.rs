@@ -0,0 +1,57 @@
+use futures_core::stream::Stream;
+use futures_core::task::{Waker, Poll};
+use std::pin::Pin;
+
+/// Stream for the [`once_then_pending`] function.
+#[derive(Debug, Clone)]
+#[must_use = "streams do nothing unless polled"]
+pub struct OnceThenPending<T> {
+    value: Option<T>,
+}
+
+impl<T> Unpin for OnceThenPending<T> {}
+
+/// Creates a stream which yields `value` on the first poll and then returns
+/// [`Poll::Pending`] forever, without ever waking the task.
+///
+/// Unlike [`stream::once`](futures_util::stream::once), which ends after its
+/// item, this stream never ends, and unlike a stream that is always pending
+/// it does produce an item. This makes it useful for testing wakeup logic:
+/// anything waiting on this stream after the first item will only make
+/// progress if something else wakes the task, so lost wakeups show up as
+/// hangs or unexpected `Pending`s.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(futures_api)]
+/// use futures::stream::StreamExt;
+/// use futures::task::Poll;
+/// use futures_test::stream::once_then_pending;
+/// use futures_test::task::new_count_waker;
+///
+/// let mut stream = once_then_pending(5);
+/// let (lw, count) = new_count_waker();
+///
+/// assert_eq!(stream.poll_next_unpin(&lw), Poll::Ready(Some(5)));
+/// assert_eq!(stream.poll_next_unpin(&lw), Poll::Pending);
+/// assert_eq!(stream.poll_next_unpin(&lw), Poll::Pending);
+/// assert_eq!(count, 0);
+/// ```
+pub fn once_then_pending<T>(value: T) -> OnceThenPending<T> {
+    OnceThenPending { value: Some(value) }
+}
+
+impl<T> Stream for OnceThenPending<T> {
+    type Item = T;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        _: &Waker,
+    ) -> Poll<Option<T>> {
+        match self.value.take() {
+            Some(value) => Poll::Ready(Some(value)),
+            None => Poll::Pending,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+#![feature(test, futures_api)]
+
+extern crate test;
+use crate::test::Bencher;
+
+use futures::executor::block_on;
+use futures::future;
+use futures::stream::{self, StreamExt};
+
+const LEN: u64 = 64 * 1024;
+
+#[bench]
+fn map_map(b: &mut Bencher) {
+    b.iter(|| {
+        let stream = stream::iter(0..LEN)
+            .map(|x| x.wrapping_mul(3))
+            .map(|x| x ^ 0x55);
+        block_on(stream.fold(0u64, |acc, x| future::ready(acc.wrapping_add(x))))
+    });
+}
+
+#[bench]
+fn map_composed(b: &mut Bencher) {
+    b.iter(|| {
+        let stream = stream::iter(0..LEN)
+            .map_composed(|x| x.wrapping_mul(3), |x| x ^ 0x55);
+        block_on(stream.fold(0u64, |acc, x| future::ready(acc.wrapping_add(x))))
+    });
+}
@@ -0,0 +1,78 @@
+#![feature(test, futures_api)]
+
+extern crate test;
+use crate::test::Bencher;
+
+use futures::executor::block_on;
+use futures::io::{AsyncRead, AsyncReadExt};
+use futures::task::{Poll, Waker};
+use futures_test::task::noop_waker_ref;
+use std::io::{self, Cursor};
+
+const LEN: usize = 1024 * 1024;
+
+/// Counts the calls to `poll_read` on the wrapped reader.
+struct CountReads<R> {
+    inner: R,
+    reads: usize,
+}
+
+impl<R: AsyncRead> AsyncRead for CountReads<R> {
+    fn poll_read(&mut self, waker: &Waker, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        self.reads += 1;
+        self.inner.poll_read(waker, buf)
+    }
+}
+
+// The strategy `read_to_end` used before the reservation grew adaptively:
+// reserve a fixed 32 bytes every time the buffer is full.
+fn read_to_end_fixed_chunk<R: AsyncRead>(rd: &mut R, buf: &mut Vec<u8>) {
+    let mut len = buf.len();
+    loop {
+        if len == buf.len() {
+            buf.reserve(32);
+            let capacity = buf.capacity();
+            buf.resize(capacity, 0);
+        }
+        match rd.poll_read(noop_waker_ref(), &mut buf[len..]) {
+            Poll::Ready(Ok(0)) => break,
+            Poll::Ready(Ok(n)) => len += n,
+            Poll::Ready(Err(e)) => panic!("read failed: {}", e),
+            Poll::Pending => unreachable!("`Cursor` is always ready"),
+        }
+    }
+    buf.truncate(len);
+}
+
+fn bench_reads<F>(b: &mut Bencher, name: &str, mut read: F)
+    where F: FnMut(&mut CountReads<Cursor<&[u8]>>, &mut Vec<u8>),
+{
+    let data = vec![0u8; LEN];
+    let mut reads = 0;
+
+    b.iter(|| {
+        let mut reader = CountReads { inner: Cursor::new(&data[..]), reads: 0 };
+        let mut out = Vec::new();
+        read(&mut reader, &mut out);
+        reads = reader.reads;
+        out
+    });
+    eprintln!("{}: {} poll_read calls for {} bytes", name, reads, LEN);
+}
+
+#[bench]
+fn read_to_end_1mb_before(b: &mut Bencher) {
+    bench_reads(b, "before", |reader, out| read_to_end_fixed_chunk(reader, out));
+}
+
+#[bench]
+fn read_to_end_1mb_after(b: &mut Bencher) {
+    bench_reads(b, "after", |reader, out| block_on(reader.read_to_end(out)).unwrap());
+}
+
+#[bench]
+fn read_to_end_with_capacity_1mb(b: &mut Bencher) {
+    bench_reads(b, "with_capacity", |reader, out| {
+        block_on(reader.read_to_end_with_capacity(out, LEN)).unwrap()
+    });
+}
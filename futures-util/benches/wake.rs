@@ -0,0 +1,73 @@
+#![feature(test, futures_api)]
+
+extern crate test;
+use crate::test::Bencher;
+
+use futures::task::{ArcWake, Waker, RawWaker, RawWakerVTable};
+use std::mem;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const NUM: usize = 1024;
+
+struct CountWake {
+    count: AtomicUsize,
+}
+
+impl ArcWake for CountWake {
+    fn wake(arc_self: &Arc<Self>) {
+        arc_self.count.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+// The way Arc-based wakers used to be woken: rebuild the `Arc` from the raw
+// pointer and forget it again afterwards.
+unsafe fn clone_forget_raw(data: *const ()) -> RawWaker {
+    let arc: Arc<CountWake> = Arc::from_raw(data as *const CountWake);
+    mem::forget(arc.clone());
+    mem::forget(arc);
+    RawWaker::new(data, &FORGET_VTABLE)
+}
+
+unsafe fn drop_forget_raw(data: *const ()) {
+    drop(Arc::<CountWake>::from_raw(data as *const CountWake))
+}
+
+unsafe fn wake_forget_raw(data: *const ()) {
+    let arc: Arc<CountWake> = Arc::from_raw(data as *const CountWake);
+    ArcWake::wake(&arc);
+    mem::forget(arc);
+}
+
+static FORGET_VTABLE: RawWakerVTable = RawWakerVTable {
+    clone: clone_forget_raw,
+    drop: drop_forget_raw,
+    wake: wake_forget_raw,
+};
+
+#[bench]
+fn wake_from_raw_and_forget(b: &mut Bencher) {
+    let wake = Arc::new(CountWake { count: AtomicUsize::new(0) });
+    let ptr = Arc::into_raw(wake.clone()) as *const ();
+    let waker = unsafe { Waker::new_unchecked(RawWaker::new(ptr, &FORGET_VTABLE)) };
+
+    b.iter(|| {
+        for _ in 0..NUM {
+            waker.wake();
+        }
+    });
+    test::black_box(wake.count.load(Ordering::Relaxed));
+}
+
+#[bench]
+fn wake_borrowed_arc(b: &mut Bencher) {
+    let wake = Arc::new(CountWake { count: AtomicUsize::new(0) });
+    let waker = ArcWake::into_waker(wake.clone());
+
+    b.iter(|| {
+        for _ in 0..NUM {
+            waker.wake();
+        }
+    });
+    test::black_box(wake.count.load(Ordering::Relaxed));
+}
@@ -27,6 +27,11 @@ mod join;
 mod select_mod;
 pub use self::select_mod::*;
 
+// Primary export is a macro
+#[macro_use]
+mod stream_mod;
+pub use self::stream_mod::*;
+
 #[doc(hidden)]
 #[inline(always)]
 pub fn assert_unpin<T: Unpin>(_: &T) {}
@@ -0,0 +1,184 @@
+//! The `stream` macro.
+
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Waker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use std::sync::{Arc, Mutex};
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! document_stream_macro {
+    ($item:item) => {
+        /// Creates a stream from a block of asynchronous code, in which items
+        /// are produced with the `yield_item!` macro.
+        ///
+        /// The block is run as an `async move` block: it can `await!` other
+        /// futures, and every `yield_item!(value)` hands `value` to the
+        /// consumer of the stream and suspends the block until the next item
+        /// is requested. The stream ends once the block completes.
+        ///
+        /// This macro emulates generators, which aren't available yet, and
+        /// therefore comes with a few limitations:
+        ///
+        /// - `yield_item!` can only be used directly in the body of the
+        ///   `stream!` block, not in closures or nested `async` blocks defined
+        ///   within it.
+        /// - The block must evaluate to `()`, so errors can't be propagated
+        ///   with `?`. Yield a `Result` instead.
+        /// - Each item is handed over through a shared slot guarded by a
+        ///   mutex, which costs a little more than a hand-written stream.
+        /// - The type of the items can't be inferred if the block never uses
+        ///   `yield_item!`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(async_await, await_macro, futures_api)]
+        /// # futures::executor::block_on(async {
+        /// use futures::future;
+        /// use futures::stream;
+        /// use futures::stream::StreamExt;
+        ///
+        /// let s = stream! {
+        ///     for i in 1..=3 {
+        ///         let doubled = await!(future::ready(i * 2));
+        ///         yield_item!(doubled);
+        ///     }
+        /// };
+        ///
+        /// assert_eq!(await!(s.collect::<Vec<_>>()), vec![2, 4, 6]);
+        /// # });
+        /// ```
+        $item
+    }
+}
+
+document_stream_macro! {
+    #[macro_export]
+    macro_rules! stream {
+        ($($body:tt)*) => {
+            $crate::__stream_inner!(($) $($body)*)
+        }
+    }
+}
+
+// The `$` token is passed in from `stream!` so that the `yield_item!` macro
+// can be defined from within this macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __stream_inner {
+    (($d:tt) $($body:tt)*) => {
+        $crate::async_await::generate(move |__yielder| async move {
+            #[allow(unused_macros)]
+            macro_rules! yield_item {
+                ($d item:expr) => {
+                    await!(__yielder.yield_item($d item))
+                }
+            }
+
+            $($body)*
+        })
+    }
+}
+
+#[doc(hidden)]
+pub fn generate<T, F, Fut>(f: F) -> Generate<T, Fut>
+where
+    F: FnOnce(Yielder<T>) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let slot = Arc::new(Mutex::new(None));
+    let future = f(Yielder { slot: slot.clone() });
+    Generate { slot, future, done: false }
+}
+
+#[allow(missing_debug_implementations)]
+#[doc(hidden)]
+pub struct Yielder<T> {
+    slot: Arc<Mutex<Option<T>>>,
+}
+
+impl<T> Yielder<T> {
+    #[doc(hidden)]
+    pub fn yield_item(&self, item: T) -> YieldItem<'_, T> {
+        YieldItem { yielder: self, item: Some(item) }
+    }
+}
+
+#[allow(missing_debug_implementations)]
+#[doc(hidden)]
+pub struct YieldItem<'a, T> {
+    yielder: &'a Yielder<T>,
+    item: Option<T>,
+}
+
+impl<T> Unpin for YieldItem<'_, T> {}
+
+impl<T> Future for YieldItem<'_, T> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, _: &Waker) -> Poll<()> {
+        match self.item.take() {
+            // Hand the item over and suspend the generating block. `Generate`
+            // returns the item without waking anyone, and polls the block
+            // again when the next item is requested.
+            Some(item) => {
+                *self.yielder.slot.lock().unwrap() = Some(item);
+                Poll::Pending
+            }
+            None => Poll::Ready(()),
+        }
+    }
+}
+
+#[allow(missing_debug_implementations)]
+#[doc(hidden)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Generate<T, Fut> {
+    slot: Arc<Mutex<Option<T>>>,
+    future: Fut,
+    done: bool,
+}
+
+impl<T, Fut: Unpin> Unpin for Generate<T, Fut> {}
+
+impl<T, Fut> Generate<T, Fut> {
+    unsafe_pinned!(future: Fut);
+    unsafe_unpinned!(done: bool);
+
+    fn take_item(&self) -> Option<T> {
+        self.slot.lock().unwrap().take()
+    }
+}
+
+impl<T, Fut> FusedStream for Generate<T, Fut> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<T, Fut> Stream for Generate<T, Fut>
+where
+    Fut: Future<Output = ()>,
+{
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, waker: &Waker) -> Poll<Option<T>> {
+        if self.done {
+            return Poll::Ready(None);
+        }
+
+        match self.as_mut().future().poll(waker) {
+            Poll::Ready(()) => {
+                *self.as_mut().done() = true;
+                Poll::Ready(None)
+            }
+            Poll::Pending => match self.take_item() {
+                Some(item) => Poll::Ready(Some(item)),
+                None => Poll::Pending,
+            },
+        }
+    }
+}
@@ -154,8 +154,13 @@ impl AbortHandle {
     /// should abort. Note that if the future is currently being polled on
     /// another thread, it will not immediately stop running. Instead, it will
     /// continue to run until its poll method returns.
-    pub fn abort(&self) {
-        self.inner.cancel.store(true, Ordering::Relaxed);
+    ///
+    /// Returns `true` if this call is the one that aborted the future, and
+    /// `false` if it had already been aborted before, which allows one-time
+    /// cleanup to be tied to the abort. The future is woken up either way.
+    pub fn abort(&self) -> bool {
+        let was_aborted = self.inner.cancel.swap(true, Ordering::Relaxed);
         self.inner.waker.wake();
+        !was_aborted
     }
 }
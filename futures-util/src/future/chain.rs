@@ -45,9 +45,13 @@ impl<Fut1, Fut2, Data> Chain<Fut1, Fut2, Data>
                     }
                 }
                 Chain::Second(fut2) => {
-                    return unsafe { Pin::new_unchecked(fut2) }.poll(waker);
+                    let output = ready!(unsafe { Pin::new_unchecked(fut2) }.poll(waker));
+                    *this = Chain::Empty; // Drop fut2
+                    return Poll::Ready(output);
+                }
+                Chain::Empty => {
+                    panic!("future must not be polled after it returned `Poll::Ready`");
                 }
-                Chain::Empty => unreachable!()
             };
 
             *this = Chain::Empty; // Drop fut1
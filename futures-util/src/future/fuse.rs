@@ -26,6 +26,55 @@ impl<Fut: Future> Fuse<Fut> {
             future: Some(f),
         }
     }
+
+    /// Creates a new `Fuse`-wrapped future which is already terminated.
+    ///
+    /// This can be useful in combination with looping and the `select!`
+    /// macro, which bypasses terminated futures: a branch can start out inert
+    /// and be armed later on by replacing the terminated future with
+    /// [`Pin::set`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::future::{self, Fuse, FusedFuture, FutureExt};
+    /// use futures::select;
+    /// use futures::stream::StreamExt;
+    /// use pin_utils::pin_mut;
+    ///
+    /// let (sender, mut stream) = mpsc::unbounded();
+    ///
+    /// sender.unbounded_send(21).unwrap();
+    /// drop(sender);
+    ///
+    /// // Use `Fuse::terminated()` to create an already-terminated future
+    /// // which may be instantiated later.
+    /// let doubler = Fuse::terminated();
+    /// pin_mut!(doubler);
+    ///
+    /// let mut total = 0;
+    /// loop {
+    ///     select! {
+    ///         doubled = doubler => total += doubled,
+    ///         x = stream.select_next_some() => {
+    ///             if doubler.is_terminated() {
+    ///                 doubler.set(future::ready(x * 2).fuse());
+    ///             }
+    ///         },
+    ///         complete => break,
+    ///     }
+    /// }
+    /// assert_eq!(total, 42);
+    /// # });
+    /// ```
+    pub fn terminated() -> Fuse<Fut> {
+        Fuse {
+            future: None,
+        }
+    }
 }
 
 impl<Fut: Future> FusedFuture for Fuse<Fut> {
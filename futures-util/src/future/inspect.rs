@@ -28,7 +28,11 @@ impl<Fut: Future, F: FnOnce(&Fut::Output)> Inspect<Fut, F> {
 impl<Fut: Future + Unpin, F> Unpin for Inspect<Fut, F> {}
 
 impl<Fut: Future + FusedFuture, F> FusedFuture for Inspect<Fut, F> {
-    fn is_terminated(&self) -> bool { self.future.is_terminated() }
+    fn is_terminated(&self) -> bool {
+        // The closure is consumed once the output has been handed out, even
+        // if it panicked.
+        self.f.is_none() || self.future.is_terminated()
+    }
 }
 
 impl<Fut, F> Future for Inspect<Fut, F>
@@ -38,12 +42,18 @@ impl<Fut, F> Future for Inspect<Fut, F>
     type Output = Fut::Output;
 
     fn poll(mut self: Pin<&mut Self>, waker: &Waker) -> Poll<Fut::Output> {
+        // Check this before polling the inner future, which has already
+        // completed if the closure was consumed.
+        assert!(self.f.is_some(), "cannot poll Inspect twice");
+
         let e = match self.as_mut().future().poll(waker) {
             Poll::Pending => return Poll::Pending,
             Poll::Ready(e) => e,
         };
 
-        let f = self.as_mut().f().take().expect("cannot poll Inspect twice");
+        // The closure is taken out before it is called, so if it panics the
+        // future is left completed and polling it again panics as well.
+        let f = self.as_mut().f().take().unwrap();
         f(&e);
         Poll::Ready(e)
     }
@@ -12,6 +12,9 @@ use futures_core::task::{Waker, Poll};
 #[doc(hidden)]
 pub use futures_core::future::FusedFuture;
 
+// re-export for `race` and `try_select`
+pub use either::Either;
+
 // Primitive futures
 mod empty;
 pub use self::empty::{empty, Empty};
@@ -50,6 +53,9 @@ pub use self::join::{Join, Join3, Join4, Join5};
 mod map;
 pub use self::map::Map;
 
+mod race;
+pub use self::race::{race, Race};
+
 // Todo
 // mod select;
 // pub use self::select::Select;
@@ -63,6 +69,9 @@ pub use self::inspect::Inspect;
 mod unit_error;
 pub use self::unit_error::UnitError;
 
+mod with_context;
+pub use self::with_context::WithContext;
+
 // Implementation details
 mod chain;
 pub(crate) use self::chain::Chain;
@@ -103,6 +112,13 @@ mod shared;
 #[cfg(feature = "std")]
 pub use self::shared::Shared;
 
+/// An owned, pinned and dynamically typed [`Future`] which isn't required to
+/// be `Send`.
+///
+/// This is the type returned by [`FutureExt::boxed_local`].
+#[cfg(feature = "std")]
+pub type LocalBoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
 impl<T: ?Sized> FutureExt for T where T: Future {}
 
 /// An extension trait for `Future`s that provides a variety of convenient
@@ -526,6 +542,9 @@ pub trait FutureExt: Future {
     /// various parts in the pipeline, without consuming the intermediate
     /// value. To do that, insert a call to `inspect`.
     ///
+    /// The returned future must not be polled again once it has completed,
+    /// or once the closure has panicked: doing so panics.
+    ///
     /// # Examples
     ///
     /// ```
@@ -545,6 +564,48 @@ pub trait FutureExt: Future {
         assert_future::<Self::Output, _>(Inspect::new(self, f))
     }
 
+    /// Enters a context around every poll of this future.
+    ///
+    /// Before each call to [`poll`](Future::poll) on the underlying future,
+    /// `enter` is called to create a guard value, which is dropped right after
+    /// the underlying future has been polled. The guard's `Drop`
+    /// implementation is thus where the context is exited. This can be used
+    /// to propagate things like tracing spans or logging contexts across the
+    /// points where the future yields, without tying this crate to a
+    /// particular tracing library.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future::{self, FutureExt};
+    /// use std::cell::Cell;
+    ///
+    /// struct Exit<'a>(&'a Cell<usize>);
+    ///
+    /// impl Drop for Exit<'_> {
+    ///     fn drop(&mut self) {
+    ///         self.0.set(self.0.get() - 1);
+    ///     }
+    /// }
+    ///
+    /// let depth = Cell::new(0);
+    /// let future = future::lazy(|_| depth.get()).with_context(|| {
+    ///     depth.set(depth.get() + 1);
+    ///     Exit(&depth)
+    /// });
+    /// assert_eq!(await!(future), 1);
+    /// assert_eq!(depth.get(), 0);
+    /// # });
+    /// ```
+    fn with_context<C, F>(self, enter: F) -> WithContext<Self, F>
+        where F: FnMut() -> C,
+              Self: Sized,
+    {
+        assert_future::<Self::Output, _>(WithContext::new(self, enter))
+    }
+
     /// Catches unwinding panics while polling the future.
     ///
     /// In general, panics within a future can propagate all the way out to the
@@ -660,6 +721,33 @@ pub trait FutureExt: Future {
         Box::pin(self)
     }
 
+    /// Wrap the future in a Box, pinning it and erasing its type.
+    ///
+    /// Unlike [`FutureExt::boxed`], the concrete type of the future is
+    /// hidden behind a [`LocalBoxFuture`] trait object. The future isn't
+    /// required to be `Send`, which makes this suitable for futures driven by
+    /// single-threaded executors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::future::{self, FutureExt, LocalBoxFuture};
+    /// use std::rc::Rc;
+    ///
+    /// fn answer() -> LocalBoxFuture<'static, Rc<u32>> {
+    ///     future::ready(Rc::new(42)).boxed_local()
+    /// }
+    ///
+    /// assert_eq!(*block_on(answer()), 42);
+    /// ```
+    #[cfg(feature = "std")]
+    fn boxed_local<'a>(self) -> LocalBoxFuture<'a, Self::Output>
+        where Self: Sized + 'a
+    {
+        Box::pin(self)
+    }
+
     /// Turns a `Future` into a `TryFuture` with `Error = ()`.
     fn unit_error(self) -> UnitError<Self>
         where Self: Sized
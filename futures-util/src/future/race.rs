@@ -0,0 +1,69 @@
+use core::pin::Pin;
+use either::Either;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Waker, Poll};
+
+/// Future for the [`race`] function.
+///
+/// [`race`]: fn.race.html
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct Race<A, B> {
+    inner: Option<(A, B)>,
+}
+
+impl<A: Unpin, B: Unpin> Unpin for Race<A, B> {}
+
+/// Waits for either one of two differently-typed futures to complete,
+/// dropping the other one.
+///
+/// The returned future will finish with the output of whichever future
+/// completed first. Unlike [`try_select`](crate::try_future::try_select),
+/// the future which didn't complete isn't handed back: it is dropped as soon
+/// as the race is decided.
+///
+/// Both futures are polled in order, so `a` wins if both of them are ready
+/// at the same time.
+///
+/// # Examples
+///
+/// ```
+/// use futures::executor::block_on;
+/// use futures::future::{self, Either};
+///
+/// let a = future::empty::<u32>();
+/// let b = future::ready("done");
+///
+/// assert_eq!(block_on(future::race(a, b)), Either::Right("done"));
+/// ```
+pub fn race<A, B>(a: A, b: B) -> Race<A, B>
+    where A: Future + Unpin,
+          B: Future + Unpin,
+{
+    Race { inner: Some((a, b)) }
+}
+
+impl<A, B> FusedFuture for Race<A, B> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_none()
+    }
+}
+
+impl<A, B> Future for Race<A, B>
+    where A: Future + Unpin,
+          B: Future + Unpin,
+{
+    type Output = Either<A::Output, B::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, waker: &Waker) -> Poll<Self::Output> {
+        let (mut a, mut b) = self.inner.take().expect("cannot poll Race twice");
+        if let Poll::Ready(output) = Pin::new(&mut a).poll(waker) {
+            return Poll::Ready(Either::Left(output));
+        }
+        if let Poll::Ready(output) = Pin::new(&mut b).poll(waker) {
+            return Poll::Ready(Either::Right(output));
+        }
+        self.inner = Some((a, b));
+        Poll::Pending
+    }
+}
@@ -0,0 +1,61 @@
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Waker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`with_context`](super::FutureExt::with_context) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct WithContext<Fut, F> {
+    future: Fut,
+    enter: F,
+}
+
+impl<Fut: Unpin, F> Unpin for WithContext<Fut, F> {}
+
+impl<Fut, F, C> WithContext<Fut, F>
+    where Fut: Future,
+          F: FnMut() -> C,
+{
+    unsafe_pinned!(future: Fut);
+    unsafe_unpinned!(enter: F);
+
+    pub(super) fn new(future: Fut, enter: F) -> WithContext<Fut, F> {
+        WithContext { future, enter }
+    }
+
+    /// Acquires a reference to the underlying future that this combinator is
+    /// polling.
+    pub fn get_ref(&self) -> &Fut {
+        &self.future
+    }
+
+    /// Acquires a mutable reference to the underlying future that this
+    /// combinator is polling.
+    pub fn get_mut(&mut self) -> &mut Fut {
+        &mut self.future
+    }
+
+    /// Consumes this combinator, returning the underlying future.
+    pub fn into_inner(self) -> Fut {
+        self.future
+    }
+}
+
+impl<Fut: FusedFuture, F> FusedFuture for WithContext<Fut, F> {
+    fn is_terminated(&self) -> bool {
+        self.future.is_terminated()
+    }
+}
+
+impl<Fut, F, C> Future for WithContext<Fut, F>
+    where Fut: Future,
+          F: FnMut() -> C,
+{
+    type Output = Fut::Output;
+
+    fn poll(mut self: Pin<&mut Self>, waker: &Waker) -> Poll<Fut::Output> {
+        let _guard = (self.as_mut().enter())();
+        self.as_mut().future().poll(waker)
+    }
+}
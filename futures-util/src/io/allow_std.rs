@@ -1,5 +1,5 @@
 use futures_core::task::{Waker, Poll};
-use futures_io::{AsyncRead, AsyncWrite};
+use futures_io::{AsyncRead, AsyncBufRead, AsyncWrite};
 use std::{fmt, io};
 use std::string::String;
 use std::vec::Vec;
@@ -14,6 +14,15 @@ use std::vec::Vec;
 /// are safe to use in this context. However, using these types with
 /// `AllowStdIo` will cause the event loop to block, so they should be used
 /// with care.
+///
+/// # Blocking
+///
+/// `poll_read`, `poll_write` and friends call straight into the wrapped
+/// type's synchronous methods and always return `Poll::Ready`. **If the
+/// inner type actually blocks, the whole executor thread is blocked with
+/// it**, and no other task on that thread can make progress in the meantime.
+/// Only use this wrapper for types which never block in practice, such as
+/// in-memory buffers, or on a thread which is dedicated to blocking work.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct AllowStdIo<T>(T);
 
@@ -113,3 +122,25 @@ impl<T> AsyncRead for AllowStdIo<T> where T: io::Read {
         Poll::Ready(Ok(try_with_interrupt!(io::Read::read(&mut self.0, buf))))
     }
 }
+
+impl<T> io::BufRead for AllowStdIo<T> where T: io::BufRead {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        io::BufRead::fill_buf(&mut self.0)
+    }
+    fn consume(&mut self, amt: usize) {
+        io::BufRead::consume(&mut self.0, amt)
+    }
+}
+
+impl<T> AsyncBufRead for AllowStdIo<T> where T: io::BufRead {
+    fn poll_fill_buf<'a>(&'a mut self, _: &Waker)
+        -> Poll<io::Result<&'a [u8]>>
+    {
+        let this: *mut Self = &mut *self as *mut _;
+        Poll::Ready(Ok(try_with_interrupt!(io::BufRead::fill_buf(unsafe { &mut (*this).0 }))))
+    }
+
+    fn consume(&mut self, amt: usize) {
+        io::BufRead::consume(&mut self.0, amt)
+    }
+}
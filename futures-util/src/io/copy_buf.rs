@@ -0,0 +1,88 @@
+use futures_core::future::Future;
+use futures_core::task::{Waker, Poll};
+use futures_io::{AsyncBufRead, AsyncWrite};
+use std::io;
+use std::pin::Pin;
+
+/// A future which will copy all data from a buffered reader into a writer.
+///
+/// Created by the [`copy_buf`] function, this future will resolve to the
+/// number of bytes copied or an error if one happens.
+///
+/// [`copy_buf`]: fn.copy_buf.html
+#[derive(Debug)]
+pub struct CopyBuf<'a, R: ?Sized, W: ?Sized> {
+    reader: &'a mut R,
+    writer: &'a mut W,
+    amt: u64,
+}
+
+// No projections of Pin<&mut CopyBuf> into Pin<&mut Field> are ever done.
+impl<R: ?Sized, W: ?Sized> Unpin for CopyBuf<'_, R, W> {}
+
+/// Creates a future which copies all the bytes from a buffered reader into a
+/// writer.
+///
+/// Unlike [`copy_into`](super::AsyncReadExt::copy_into), this doesn't
+/// allocate a buffer of its own: the data is written straight out of the
+/// reader's internal buffer, and only the bytes that were actually written
+/// are consumed from it. This future will only complete once the `reader`
+/// has hit EOF and all bytes have been written to and flushed from the
+/// `writer` provided.
+///
+/// On success the number of bytes is returned.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await, await_macro, futures_api)]
+/// # futures::executor::block_on(async {
+/// use futures::io;
+/// use std::io::Cursor;
+///
+/// let mut reader = Cursor::new([1, 2, 3, 4]);
+/// let mut writer = Cursor::new([0u8; 5]);
+///
+/// let bytes = await!(io::copy_buf(&mut reader, &mut writer))?;
+///
+/// assert_eq!(bytes, 4);
+/// assert_eq!(writer.into_inner(), [1, 2, 3, 4, 0]);
+/// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+/// ```
+pub fn copy_buf<'a, R, W>(reader: &'a mut R, writer: &'a mut W) -> CopyBuf<'a, R, W>
+    where R: AsyncBufRead + ?Sized,
+          W: AsyncWrite + ?Sized,
+{
+    CopyBuf {
+        reader,
+        writer,
+        amt: 0,
+    }
+}
+
+impl<R, W> Future for CopyBuf<'_, R, W>
+    where R: AsyncBufRead + ?Sized,
+          W: AsyncWrite + ?Sized,
+{
+    type Output = io::Result<u64>;
+
+    fn poll(mut self: Pin<&mut Self>, waker: &Waker) -> Poll<Self::Output> {
+        let this = &mut *self;
+        loop {
+            let buffer = try_ready!(this.reader.poll_fill_buf(waker));
+            if buffer.is_empty() {
+                // The reader hit EOF, so flush out the data and finish the
+                // transfer.
+                try_ready!(this.writer.poll_flush(waker));
+                return Poll::Ready(Ok(this.amt));
+            }
+
+            let i = try_ready!(this.writer.poll_write(waker, buffer));
+            if i == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()))
+            }
+            this.amt += i as u64;
+            this.reader.consume(i);
+        }
+    }
+}
@@ -0,0 +1,26 @@
+pub use std::io::Empty;
+
+/// Constructs a new handle to an empty reader.
+///
+/// All reads from the returned reader will return `Poll::Ready(Ok(0))`.
+///
+/// This is the asynchronous analog of [`std::io::empty`]: the returned
+/// reader implements [`AsyncRead`](crate::io::AsyncRead) and
+/// [`AsyncBufRead`](crate::io::AsyncBufRead).
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await, await_macro, futures_api)]
+/// # futures::executor::block_on(async {
+/// use futures::io::{self, AsyncReadExt};
+///
+/// let mut buffer = Vec::new();
+/// let mut reader = io::empty();
+/// await!(reader.read_to_end(&mut buffer))?;
+/// assert!(buffer.is_empty());
+/// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+/// ```
+pub fn empty() -> Empty {
+    std::io::empty()
+}
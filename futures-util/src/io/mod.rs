@@ -7,7 +7,7 @@
 
 use std::vec::Vec;
 
-pub use futures_io::{AsyncRead, AsyncWrite, IoVec};
+pub use futures_io::{AsyncRead, AsyncBufRead, AsyncWrite, IoVec};
 
 #[cfg(feature = "io-compat")] use crate::compat::Compat;
 
@@ -20,9 +20,15 @@ pub use futures_io::{AsyncRead, AsyncWrite, IoVec};
 mod allow_std;
 pub use self::allow_std::AllowStdIo;
 
+mod copy_buf;
+pub use self::copy_buf::{copy_buf, CopyBuf};
+
 mod copy_into;
 pub use self::copy_into::CopyInto;
 
+mod empty;
+pub use self::empty::{empty, Empty};
+
 mod flush;
 pub use self::flush::Flush;
 
@@ -35,9 +41,15 @@ pub use self::read_exact::ReadExact;
 mod read_to_end;
 pub use self::read_to_end::ReadToEnd;
 
+mod repeat;
+pub use self::repeat::{repeat, Repeat};
+
 mod close;
 pub use self::close::Close;
 
+mod sink;
+pub use self::sink::{sink, Sink};
+
 mod split;
 pub use self::split::{ReadHalf, WriteHalf};
 
@@ -47,6 +59,9 @@ pub use self::window::Window;
 mod write_all;
 pub use self::write_all::WriteAll;
 
+mod write_all_and_flush;
+pub use self::write_all_and_flush::WriteAllAndFlush;
+
 /// An extension trait which adds utility methods to `AsyncRead` types.
 pub trait AsyncReadExt: AsyncRead {
     /// Creates a future which copies all the bytes from one object to another.
@@ -187,6 +202,37 @@ pub trait AsyncReadExt: AsyncRead {
         ReadToEnd::new(self, buf)
     }
 
+    /// Creates a future which will read all the bytes from this `AsyncRead`,
+    /// reserving space for at least `capacity` more bytes in `buf` up front.
+    ///
+    /// This behaves like [`read_to_end`](AsyncReadExt::read_to_end), but can
+    /// save a number of reallocations and reads when the size of the source
+    /// is known, or can be estimated, in advance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::io::AsyncReadExt;
+    /// use std::io::Cursor;
+    ///
+    /// let mut reader = Cursor::new(vec![7u8; 1024]);
+    /// let mut output = Vec::new();
+    ///
+    /// await!(reader.read_to_end_with_capacity(&mut output, 1024))?;
+    ///
+    /// assert_eq!(output, vec![7u8; 1024]);
+    /// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn read_to_end_with_capacity<'a>(
+        &'a mut self,
+        buf: &'a mut Vec<u8>,
+        capacity: usize,
+    ) -> ReadToEnd<'a, Self> {
+        ReadToEnd::with_capacity(self, buf, capacity)
+    }
+
     /// Helper method for splitting this read/write object into two halves.
     ///
     /// The two halves returned implement the `AsyncRead` and `AsyncWrite`
@@ -300,6 +346,38 @@ pub trait AsyncWriteExt: AsyncWrite {
         WriteAll::new(self, buf)
     }
 
+    /// Write data into this object and flush it.
+    ///
+    /// Creates a future that will write the entire contents of the buffer `buf`
+    /// into this `AsyncWrite`, like [`write_all`](AsyncWriteExt::write_all),
+    /// and then flush it, like [`flush`](AsyncWriteExt::flush).
+    ///
+    /// The returned future will not complete until all the data has been
+    /// written and flushed. The object is not flushed if writing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AllowStdIo, AsyncWriteExt};
+    /// use std::io::{BufWriter, Cursor};
+    ///
+    /// let mut output = [0u8; 5];
+    ///
+    /// {
+    ///     let mut writer = Cursor::new(&mut output[..]);
+    ///     let mut buffered = AllowStdIo::new(BufWriter::new(writer));
+    ///     await!(buffered.write_all_and_flush(&[1, 2, 3, 4]))?;
+    /// }
+    ///
+    /// assert_eq!(output, [1, 2, 3, 4, 0]);
+    /// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn write_all_and_flush<'a>(&'a mut self, buf: &'a [u8]) -> WriteAllAndFlush<'a, Self> {
+        WriteAllAndFlush::new(self, buf)
+    }
+
     /// Wraps an [`AsyncWrite`] in a compatibility wrapper that allows it to be
     /// used as a futures 0.1 / tokio-io 0.1 `AsyncWrite`.
     /// Requires the `io-compat` feature to enable.
@@ -1,6 +1,7 @@
 use futures_core::future::Future;
 use futures_core::task::{Waker, Poll};
 use futures_io::AsyncRead;
+use std::cmp;
 use std::io;
 use std::pin::Pin;
 use std::vec::Vec;
@@ -15,6 +16,7 @@ use std::vec::Vec;
 pub struct ReadToEnd<'a, R: ?Sized> {
     reader: &'a mut R,
     buf: &'a mut Vec<u8>,
+    chunk: usize,
 }
 
 // We never project pinning to fields
@@ -22,10 +24,25 @@ impl<R: ?Sized> Unpin for ReadToEnd<'_, R> {}
 
 impl<'a, R: AsyncRead + ?Sized> ReadToEnd<'a, R> {
     pub(super) fn new(reader: &'a mut R, buf: &'a mut Vec<u8>) -> Self {
-        ReadToEnd { reader, buf }
+        ReadToEnd { reader, buf, chunk: MIN_CHUNK_SIZE }
+    }
+
+    pub(super) fn with_capacity(
+        reader: &'a mut R,
+        buf: &'a mut Vec<u8>,
+        capacity: usize,
+    ) -> Self {
+        buf.reserve(capacity);
+        Self::new(reader, buf)
     }
 }
 
+// The amount of space reserved whenever the buffer is full starts out at
+// `MIN_CHUNK_SIZE` and doubles every time a read fills all of the available
+// space, up to `MAX_CHUNK_SIZE`.
+const MIN_CHUNK_SIZE: usize = 32;
+const MAX_CHUNK_SIZE: usize = 8 * 1024;
+
 struct Guard<'a> { buf: &'a mut Vec<u8>, len: usize }
 
 impl Drop for Guard<'_> {
@@ -39,7 +56,9 @@ impl Drop for Guard<'_> {
 // has 4 bytes while still making large reads if the reader does have a ton
 // of data to return. Simply tacking on an extra DEFAULT_BUF_SIZE space every
 // time is 4,500 times (!) slower than this if the reader has a very small
-// amount of data to return.
+// amount of data to return. Conversely, growing the reservation as long as
+// the reader keeps filling the buffer keeps the number of reads down for
+// large sources.
 //
 // Because we're extending the buffer with uninitialized data for trusted
 // readers, we need to make sure to truncate that if any of this panics.
@@ -47,13 +66,14 @@ fn read_to_end_internal<R: AsyncRead + ?Sized>(
     rd: &mut R,
     waker: &Waker,
     buf: &mut Vec<u8>,
+    chunk: &mut usize,
 ) -> Poll<io::Result<()>> {
     let mut g = Guard { len: buf.len(), buf };
     let ret;
     loop {
         if g.len == g.buf.len() {
             unsafe {
-                g.buf.reserve(32);
+                g.buf.reserve(*chunk);
                 let capacity = g.buf.capacity();
                 g.buf.set_len(capacity);
                 rd.initializer().initialize(&mut g.buf[g.len..]);
@@ -65,7 +85,12 @@ fn read_to_end_internal<R: AsyncRead + ?Sized>(
                 ret = Poll::Ready(Ok(()));
                 break;
             }
-            Poll::Ready(Ok(n)) => g.len += n,
+            Poll::Ready(Ok(n)) => {
+                g.len += n;
+                if g.len == g.buf.len() {
+                    *chunk = cmp::min(*chunk * 2, MAX_CHUNK_SIZE);
+                }
+            }
             Poll::Pending => return Poll::Pending,
             Poll::Ready(Err(e)) => {
                 ret = Poll::Ready(Err(e));
@@ -84,6 +109,6 @@ impl<A> Future for ReadToEnd<'_, A>
 
     fn poll(mut self: Pin<&mut Self>, waker: &Waker) -> Poll<Self::Output> {
         let this = &mut *self;
-        read_to_end_internal(this.reader, waker, this.buf)
+        read_to_end_internal(this.reader, waker, this.buf, &mut this.chunk)
     }
 }
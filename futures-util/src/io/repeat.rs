@@ -0,0 +1,26 @@
+pub use std::io::Repeat;
+
+/// Creates an instance of a reader that infinitely repeats one byte.
+///
+/// All reads from this reader will succeed by filling the specified buffer
+/// with the given byte.
+///
+/// This is the asynchronous analog of [`std::io::repeat`]: the returned
+/// reader implements [`AsyncRead`](crate::io::AsyncRead).
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await, await_macro, futures_api)]
+/// # futures::executor::block_on(async {
+/// use futures::io::{self, AsyncReadExt};
+///
+/// let mut buffer = [0; 3];
+/// let mut reader = io::repeat(0b101);
+/// await!(reader.read_exact(&mut buffer))?;
+/// assert_eq!(buffer, [0b101, 0b101, 0b101]);
+/// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+/// ```
+pub fn repeat(byte: u8) -> Repeat {
+    std::io::repeat(byte)
+}
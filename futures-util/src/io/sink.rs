@@ -0,0 +1,26 @@
+pub use std::io::Sink;
+
+/// Creates an instance of a writer which will successfully consume all data.
+///
+/// All calls to `poll_write` on the returned instance will return
+/// `Poll::Ready(Ok(buf.len()))` and the contents of the buffer will not be
+/// inspected.
+///
+/// This is the asynchronous analog of [`std::io::sink`]: the returned writer
+/// implements [`AsyncWrite`](crate::io::AsyncWrite).
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await, await_macro, futures_api)]
+/// # futures::executor::block_on(async {
+/// use futures::io::{self, AsyncWriteExt};
+///
+/// let buffer = vec![1, 2, 3, 5, 8];
+/// let mut writer = io::sink();
+/// await!(writer.write_all(&buffer))?;
+/// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+/// ```
+pub fn sink() -> Sink {
+    std::io::sink()
+}
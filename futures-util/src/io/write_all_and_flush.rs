@@ -0,0 +1,57 @@
+use futures_core::future::Future;
+use futures_core::task::{Waker, Poll};
+use futures_io::AsyncWrite;
+use std::io;
+use std::mem;
+use std::pin::Pin;
+
+/// A future used to write the entire contents of some data to a stream and
+/// then flush it.
+///
+/// This is created by the [`write_all_and_flush`] top-level method.
+///
+/// [`write_all_and_flush`]: fn.write_all_and_flush.html
+#[derive(Debug)]
+pub struct WriteAllAndFlush<'a, W: ?Sized> {
+    writer: &'a mut W,
+    buf: &'a [u8],
+    state: State,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Writing,
+    Flushing,
+}
+
+// Pinning is never projected to fields
+impl<W: ?Sized> Unpin for WriteAllAndFlush<'_, W> {}
+
+impl<'a, W: AsyncWrite + ?Sized> WriteAllAndFlush<'a, W> {
+    pub(super) fn new(writer: &'a mut W, buf: &'a [u8]) -> Self {
+        WriteAllAndFlush { writer, buf, state: State::Writing }
+    }
+}
+
+impl<W: AsyncWrite + ?Sized> Future for WriteAllAndFlush<'_, W> {
+    type Output = io::Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, waker: &Waker) -> Poll<io::Result<()>> {
+        let this = &mut *self;
+        if this.state == State::Writing {
+            while !this.buf.is_empty() {
+                let n = try_ready!(this.writer.poll_write(waker, this.buf));
+                {
+                    let (_, rest) = mem::replace(&mut this.buf, &[]).split_at(n);
+                    this.buf = rest;
+                }
+                if n == 0 {
+                    return Poll::Ready(Err(io::ErrorKind::WriteZero.into()))
+                }
+            }
+            this.state = State::Flushing;
+        }
+
+        this.writer.poll_flush(waker)
+    }
+}
@@ -215,6 +215,10 @@ pub trait SinkExt: Sink {
     /// Doing `sink.send_all(stream)` is roughly equivalent to
     /// `stream.forward(sink)`. The returned future will exhaust all items from
     /// `stream` and send them to `self`.
+    ///
+    /// If the sink returns an error, the future resolves to that error right
+    /// away. Items which were already sent to the sink are not flushed in that
+    /// case.
     fn send_all<'a, St>(
         &'a mut self,
         stream: &'a mut St
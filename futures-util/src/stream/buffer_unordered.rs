@@ -1,9 +1,10 @@
-use crate::stream::{Fuse, FuturesUnordered};
+use crate::stream::{Buffering, Fuse, FuturesUnordered, poll_buffered};
+use core::num::NonZeroUsize;
 use futures_core::future::Future;
 use futures_core::stream::{Stream, FusedStream};
 use futures_core::task::{Waker, Poll};
 use futures_sink::Sink;
-use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use pin_utils::unsafe_pinned;
 use std::fmt;
 use std::pin::Pin;
 
@@ -21,7 +22,7 @@ where
 {
     stream: Fuse<St>,
     in_progress_queue: FuturesUnordered<St::Item>,
-    max: usize,
+    max: Option<NonZeroUsize>,
 }
 
 impl<St> Unpin for BufferUnordered<St>
@@ -50,7 +51,6 @@ where
     St::Item: Future,
 {
     unsafe_pinned!(stream: Fuse<St>);
-    unsafe_unpinned!(in_progress_queue: FuturesUnordered<St::Item>);
 
     pub(super) fn new(stream: St, n: usize) -> BufferUnordered<St>
     where
@@ -60,7 +60,8 @@ where
         BufferUnordered {
             stream: super::Fuse::new(stream),
             in_progress_queue: FuturesUnordered::new(),
-            max: n,
+            // Note: `n` = 0 gets ignored.
+            max: NonZeroUsize::new(n),
         }
     }
 
@@ -106,30 +107,24 @@ where
     type Item = <St::Item as Future>::Output;
 
     fn poll_next(
-        mut self: Pin<&mut Self>,
+        self: Pin<&mut Self>,
         waker: &Waker,
     ) -> Poll<Option<Self::Item>> {
-        // First up, try to spawn off as many futures as possible by filling up
-        // our slab of futures.
-        while self.in_progress_queue.len() < self.max {
-            match self.as_mut().stream().poll_next(waker) {
-                Poll::Ready(Some(fut)) => self.as_mut().in_progress_queue().push(fut),
-                Poll::Ready(None) | Poll::Pending => break,
-            }
-        }
+        poll_buffered(self, waker)
+    }
+}
 
-        // Attempt to pull the next value from the in_progress_queue
-        match Pin::new(self.as_mut().in_progress_queue()).poll_next(waker) {
-            x @ Poll::Pending | x @ Poll::Ready(Some(_)) => return x,
-            Poll::Ready(None) => {}
-        }
+impl<St> Buffering for BufferUnordered<St>
+where
+    St: Stream,
+    St::Item: Future,
+{
+    type Stream = St;
+    type Future = St::Item;
+    type Queue = FuturesUnordered<St::Item>;
 
-        // If more values are still coming from the stream, we're not done yet
-        if self.stream.is_done() {
-            Poll::Ready(None)
-        } else {
-            Poll::Pending
-        }
+    fn fields(&mut self) -> (&mut Fuse<Self::Stream>, &mut Self::Queue, Option<NonZeroUsize>) {
+        (&mut self.stream, &mut self.in_progress_queue, self.max)
     }
 }
 
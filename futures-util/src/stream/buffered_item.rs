@@ -0,0 +1,118 @@
+use crate::stream::{Fuse, FuturesOrdered, FuturesUnordered};
+use crate::try_future::{IntoFuture, TryFutureExt};
+use core::num::NonZeroUsize;
+use futures_core::future::{Future, TryFuture};
+use futures_core::stream::Stream;
+use futures_core::task::{Waker, Poll};
+use std::pin::Pin;
+
+/// An item of a stream which can be buffered by the buffering adaptors.
+///
+/// `Fut` is the future which gets pushed to the queue of in-progress futures.
+/// Plain futures are buffered as is, while the items of a `TryStream` either
+/// hold a `TryFuture` to buffer or an error to yield right away.
+pub(crate) trait BufferedItem<Fut: Future> {
+    /// Turns the item into the future to buffer, or into the output to yield
+    /// without buffering anything.
+    fn into_buffered(self) -> Result<Fut, Fut::Output>;
+}
+
+impl<Fut: Future> BufferedItem<Fut> for Fut {
+    fn into_buffered(self) -> Result<Fut, Fut::Output> {
+        Ok(self)
+    }
+}
+
+impl<Fut: TryFuture> BufferedItem<IntoFuture<Fut>> for Result<Fut, Fut::Error> {
+    fn into_buffered(self) -> Result<IntoFuture<Fut>, Result<Fut::Ok, Fut::Error>> {
+        match self {
+            Ok(fut) => Ok(fut.into_future()),
+            Err(e) => Err(Err(e)),
+        }
+    }
+}
+
+/// The queue in which the buffering adaptors keep their in-progress futures.
+/// It decides the order in which the outputs are yielded.
+pub(crate) trait InProgressQueue<Fut: Future>: Stream<Item = Fut::Output> + Unpin {
+    fn push(&mut self, future: Fut);
+
+    fn len(&self) -> usize;
+}
+
+impl<Fut: Future> InProgressQueue<Fut> for FuturesUnordered<Fut> {
+    fn push(&mut self, future: Fut) {
+        FuturesUnordered::push(self, future)
+    }
+
+    fn len(&self) -> usize {
+        FuturesUnordered::len(self)
+    }
+}
+
+impl<Fut: Future> InProgressQueue<Fut> for FuturesOrdered<Fut> {
+    fn push(&mut self, future: Fut) {
+        FuturesOrdered::push(self, future)
+    }
+
+    fn len(&self) -> usize {
+        FuturesOrdered::len(self)
+    }
+}
+
+/// Gives [`poll_buffered`] access to the fields of a buffering adaptor.
+pub(crate) trait Buffering {
+    type Stream: Stream;
+    type Future: Future;
+    type Queue: InProgressQueue<Self::Future>;
+
+    /// Borrows the stream the futures are pulled from, the queue of
+    /// in-progress futures and the limit on the size of that queue. A limit
+    /// of `None` means there is no limit.
+    fn fields(&mut self) -> (&mut Fuse<Self::Stream>, &mut Self::Queue, Option<NonZeroUsize>);
+}
+
+/// The `poll_next` implementation shared by the buffering adaptors.
+///
+/// Pulls as many items out of the adaptor's stream as its limit allows and
+/// then polls its queue. The stream ends once the adaptor's stream has ended
+/// and all of the buffered futures have completed.
+pub(crate) fn poll_buffered<B>(
+    this: Pin<&mut B>,
+    waker: &Waker,
+) -> Poll<Option<<B::Future as Future>::Output>>
+where
+    B: Buffering,
+    <B::Stream as Stream>::Item: BufferedItem<B::Future>,
+{
+    // Safe to call `get_unchecked_mut` because we won't move the stream,
+    // and the queue of futures is `Unpin`.
+    let (stream, queue, max) = unsafe { Pin::get_unchecked_mut(this) }.fields();
+    let mut stream = unsafe { Pin::new_unchecked(stream) };
+
+    // First up, try to spawn off as many futures as possible by filling up
+    // our queue of futures. Outputs which don't need buffering, such as
+    // errors from the stream, are yielded immediately.
+    while max.map(|max| max.get() > queue.len()).unwrap_or(true) {
+        match stream.as_mut().poll_next(waker) {
+            Poll::Ready(Some(item)) => match item.into_buffered() {
+                Ok(fut) => queue.push(fut),
+                Err(output) => return Poll::Ready(Some(output)),
+            },
+            Poll::Ready(None) | Poll::Pending => break,
+        }
+    }
+
+    // Attempt to pull the next value from the queue
+    match Pin::new(queue).poll_next(waker) {
+        x @ Poll::Pending | x @ Poll::Ready(Some(_)) => return x,
+        Poll::Ready(None) => {}
+    }
+
+    // If more values are still coming from the stream, we're not done yet
+    if stream.is_done() {
+        Poll::Ready(None)
+    } else {
+        Poll::Pending
+    }
+}
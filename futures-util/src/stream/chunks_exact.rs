@@ -0,0 +1,101 @@
+use crate::stream::Fuse;
+use futures_core::stream::Stream;
+use futures_core::task::{Waker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use std::mem;
+use std::pin::Pin;
+use std::prelude::v1::*;
+
+/// An adaptor that chunks up elements in vectors of an exact size.
+///
+/// This adaptor will buffer up a list of items in the stream and pass on the
+/// vector used for buffering when a specified capacity has been reached. Any
+/// leftover items at the end of the stream are not yielded, but can be
+/// retrieved with `into_remainder`. This is created by the
+/// `Stream::chunks_exact` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct ChunksExact<St: Stream> {
+    stream: Fuse<St>,
+    items: Vec<St::Item>,
+    cap: usize,
+}
+
+impl<St: Unpin + Stream> Unpin for ChunksExact<St> {}
+
+impl<St: Stream> ChunksExact<St> where St: Stream {
+    unsafe_unpinned!(items:  Vec<St::Item>);
+    unsafe_pinned!(stream: Fuse<St>);
+
+    pub(super) fn new(stream: St, capacity: usize) -> ChunksExact<St> {
+        assert!(capacity > 0);
+
+        ChunksExact {
+            stream: super::Fuse::new(stream),
+            items: Vec::with_capacity(capacity),
+            cap: capacity,
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.stream.get_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream.into_inner()
+    }
+
+    /// Consumes this combinator, returning the items that were buffered but
+    /// not yielded because they didn't fill up a whole chunk.
+    ///
+    /// The remainder is only complete once this stream has terminated, i.e.
+    /// once `poll_next` has returned `None`. Calling this earlier returns the
+    /// items of the chunk that is currently being filled.
+    pub fn into_remainder(self) -> Vec<St::Item> {
+        self.items
+    }
+}
+
+impl<St: Stream> Stream for ChunksExact<St> {
+    type Item = Vec<St::Item>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        waker: &Waker,
+    ) -> Poll<Option<Self::Item>> {
+        let cap = self.cap;
+        loop {
+            match ready!(self.as_mut().stream().poll_next(waker)) {
+                // Push the item into the buffer and check whether it is full.
+                // If so, replace our buffer with a new and empty one and return
+                // the full one.
+                Some(item) => {
+                    self.as_mut().items().push(item);
+                    if self.items.len() >= cap {
+                        let full_buf = mem::replace(self.as_mut().items(), Vec::with_capacity(cap));
+                        return Poll::Ready(Some(full_buf))
+                    }
+                }
+
+                // Since the underlying stream ran out of values, keep whatever
+                // we have buffered as the remainder.
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
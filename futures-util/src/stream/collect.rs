@@ -1,7 +1,6 @@
-use core::mem;
 use core::pin::Pin;
 use futures_core::future::{FusedFuture, Future};
-use futures_core::stream::{FusedStream, Stream};
+use futures_core::stream::Stream;
 use futures_core::task::{Waker, Poll};
 use pin_utils::{unsafe_pinned, unsafe_unpinned};
 
@@ -12,30 +11,26 @@ use pin_utils::{unsafe_pinned, unsafe_unpinned};
 #[must_use = "streams do nothing unless polled"]
 pub struct Collect<St, C> {
     stream: St,
-    collection: C,
+    collection: Option<C>,
 }
 
 impl<St: Unpin + Stream, C> Unpin for Collect<St, C> {}
 
 impl<St: Stream, C: Default> Collect<St, C> {
     unsafe_pinned!(stream: St);
-    unsafe_unpinned!(collection: C);
-
-    fn finish(mut self: Pin<&mut Self>) -> C {
-        mem::replace(self.as_mut().collection(), Default::default())
-    }
+    unsafe_unpinned!(collection: Option<C>);
 
     pub(super) fn new(stream: St) -> Collect<St, C> {
         Collect {
             stream,
-            collection: Default::default(),
+            collection: Some(Default::default()),
         }
     }
 }
 
-impl<St: FusedStream, C> FusedFuture for Collect<St, C> {
+impl<St, C> FusedFuture for Collect<St, C> {
     fn is_terminated(&self) -> bool {
-        self.stream.is_terminated()
+        self.collection.is_none()
     }
 }
 
@@ -46,10 +41,11 @@ where St: Stream,
     type Output = C;
 
     fn poll(mut self: Pin<&mut Self>, waker: &Waker) -> Poll<C> {
+        assert!(self.collection.is_some(), "Collect polled after completion");
         loop {
             match ready!(self.as_mut().stream().poll_next(waker)) {
-                Some(e) => self.as_mut().collection().extend(Some(e)),
-                None => return Poll::Ready(self.as_mut().finish()),
+                Some(e) => self.as_mut().collection().as_mut().unwrap().extend(Some(e)),
+                None => return Poll::Ready(self.as_mut().collection().take().unwrap()),
             }
         }
     }
@@ -0,0 +1,59 @@
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::Stream;
+use futures_core::task::{Waker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// A future which collects all of the values of a stream into an existing
+/// collection.
+///
+/// This future is created by the `Stream::collect_into` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct CollectInto<'a, St, C: ?Sized> {
+    stream: St,
+    collection: &'a mut C,
+    done: bool,
+}
+
+impl<St: Unpin, C: ?Sized> Unpin for CollectInto<'_, St, C> {}
+
+impl<'a, St: Stream, C: ?Sized> CollectInto<'a, St, C> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(collection: &'a mut C);
+    unsafe_unpinned!(done: bool);
+
+    pub(super) fn new(stream: St, collection: &'a mut C) -> CollectInto<'a, St, C> {
+        CollectInto {
+            stream,
+            collection,
+            done: false,
+        }
+    }
+}
+
+impl<St, C: ?Sized> FusedFuture for CollectInto<'_, St, C> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<St, C> Future for CollectInto<'_, St, C>
+where St: Stream,
+      C: Extend<St::Item> + ?Sized,
+{
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, waker: &Waker) -> Poll<()> {
+        assert!(!self.done, "CollectInto polled after completion");
+        loop {
+            match ready!(self.as_mut().stream().poll_next(waker)) {
+                Some(e) => self.as_mut().collection().extend(Some(e)),
+                None => {
+                    *self.as_mut().done() = true;
+                    return Poll::Ready(());
+                }
+            }
+        }
+    }
+}
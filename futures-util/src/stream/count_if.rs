@@ -0,0 +1,63 @@
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::Stream;
+use futures_core::task::{Waker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// A future which counts the items of a stream matching a predicate.
+///
+/// This future is returned by the `Stream::count_if` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct CountIf<St, F> {
+    stream: St,
+    f: F,
+    count: usize,
+    done: bool,
+}
+
+impl<St: Unpin, F> Unpin for CountIf<St, F> {}
+
+impl<St, F> CountIf<St, F>
+where St: Stream,
+      F: FnMut(&St::Item) -> bool,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(count: usize);
+    unsafe_unpinned!(done: bool);
+
+    pub(super) fn new(stream: St, f: F) -> CountIf<St, F> {
+        CountIf {
+            stream,
+            f,
+            count: 0,
+            done: false,
+        }
+    }
+}
+
+impl<St, F> FusedFuture for CountIf<St, F> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<St, F> Future for CountIf<St, F>
+    where St: Stream,
+          F: FnMut(&St::Item) -> bool,
+{
+    type Output = usize;
+
+    fn poll(mut self: Pin<&mut Self>, waker: &Waker) -> Poll<usize> {
+        assert!(!self.done, "CountIf polled after completion");
+
+        while let Some(item) = ready!(self.as_mut().stream().poll_next(waker)) {
+            if (self.as_mut().f())(&item) {
+                *self.as_mut().count() += 1;
+            }
+        }
+        *self.as_mut().done() = true;
+        Poll::Ready(self.count)
+    }
+}
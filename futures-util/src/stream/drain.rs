@@ -0,0 +1,47 @@
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::Stream;
+use futures_core::task::{Waker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// A future which runs a stream to completion, discarding its items.
+///
+/// This structure is returned by the `Stream::drain` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Drain<St> {
+    stream: St,
+    done: bool,
+}
+
+impl<St: Unpin> Unpin for Drain<St> {}
+
+impl<St: Stream> Drain<St> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(done: bool);
+
+    pub(super) fn new(stream: St) -> Drain<St> {
+        Drain {
+            stream,
+            done: false,
+        }
+    }
+}
+
+impl<St> FusedFuture for Drain<St> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<St: Stream> Future for Drain<St> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, waker: &Waker) -> Poll<()> {
+        assert!(!self.done, "Drain polled after completion");
+
+        while ready!(self.as_mut().stream().poll_next(waker)).is_some() {}
+        *self.as_mut().done() = true;
+        Poll::Ready(())
+    }
+}
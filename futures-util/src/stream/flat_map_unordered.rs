@@ -0,0 +1,117 @@
+use crate::stream::{Fuse, FuturesUnordered, StreamExt, StreamFuture};
+use core::num::NonZeroUsize;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Waker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use std::pin::Pin;
+
+/// A stream combinator which maps each item of a stream to a sub-stream and
+/// flattens the sub-streams concurrently.
+///
+/// This structure is returned by the
+/// [`StreamExt::flat_map_unordered`](super::StreamExt::flat_map_unordered)
+/// method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct FlatMapUnordered<St, U, F> {
+    stream: Fuse<St>,
+    f: F,
+    in_progress: FuturesUnordered<StreamFuture<U>>,
+    limit: Option<NonZeroUsize>,
+}
+
+impl<St: Unpin, U, F> Unpin for FlatMapUnordered<St, U, F> {}
+
+impl<St, U, F> FlatMapUnordered<St, U, F>
+where St: Stream,
+      U: Stream + Unpin,
+      F: FnMut(St::Item) -> U,
+{
+    unsafe_pinned!(stream: Fuse<St>);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(in_progress: FuturesUnordered<StreamFuture<U>>);
+
+    pub(super) fn new(stream: St, limit: Option<usize>, f: F) -> FlatMapUnordered<St, U, F> {
+        FlatMapUnordered {
+            stream: super::Fuse::new(stream),
+            f,
+            in_progress: FuturesUnordered::new(),
+            // Note: `limit` = 0 gets ignored.
+            limit: limit.and_then(NonZeroUsize::new),
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.stream.get_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream.into_inner()
+    }
+}
+
+impl<St, U, F> FusedStream for FlatMapUnordered<St, U, F> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated() && self.in_progress.is_empty()
+    }
+}
+
+impl<St, U, F> Stream for FlatMapUnordered<St, U, F>
+where St: Stream,
+      U: Stream + Unpin,
+      F: FnMut(St::Item) -> U,
+{
+    type Item = U::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        waker: &Waker,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            // Start as many sub-streams as the limit allows.
+            while self.limit.map(|limit| limit.get() > self.in_progress.len()).unwrap_or(true) {
+                match self.as_mut().stream().poll_next(waker) {
+                    Poll::Ready(Some(item)) => {
+                        let sub_stream = (self.as_mut().f())(item);
+                        self.as_mut().in_progress().push(sub_stream.into_future());
+                    }
+                    Poll::Ready(None) | Poll::Pending => break,
+                }
+            }
+
+            match self.as_mut().in_progress().poll_next_unpin(waker) {
+                Poll::Ready(Some((Some(item), remaining))) => {
+                    self.as_mut().in_progress().push(remaining.into_future());
+                    return Poll::Ready(Some(item));
+                }
+                // A sub-stream finished, which may have freed up a slot for
+                // the next one.
+                Poll::Ready(Some((None, _))) => {}
+                Poll::Ready(None) => {
+                    // If more sub-streams are still coming, we're not done yet
+                    return if self.stream.is_done() {
+                        Poll::Ready(None)
+                    } else {
+                        Poll::Pending
+                    };
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -58,6 +58,9 @@ where St: Stream,
 
 impl<St: Stream + FusedStream> FusedStream for Flatten<St> {
     fn is_terminated(&self) -> bool {
+        // The inner streams don't need to be fused: they're dropped as soon
+        // as they're exhausted, so `next` is only `Some` while it may still
+        // produce items.
         self.next.is_none() && self.stream.is_terminated()
     }
 }
@@ -83,6 +86,10 @@ impl<St> Stream for Flatten<St>
             if item.is_some() {
                 return Poll::Ready(item);
             } else {
+                // Drop the exhausted inner stream right away and move on to
+                // the next one within the same poll, so empty inner streams
+                // never stall the combinator and `is_terminated` only has to
+                // look at `next`.
                 self.as_mut().next().set(None);
             }
         }
@@ -55,7 +55,11 @@ impl<St, Fut, T, F> Future for Fold<St, Fut, T, F>
         loop {
             // we're currently processing a future to produce a new accum value
             if self.as_mut().accum().is_none() {
-                let accum = ready!(self.as_mut().future().as_pin_mut().unwrap().poll(waker));
+                let accum = ready!(
+                    self.as_mut().future().as_pin_mut()
+                       .expect("Fold polled after completion")
+                       .poll(waker)
+                );
                 *self.as_mut().accum() = Some(accum);
                 self.as_mut().future().set(None);
             }
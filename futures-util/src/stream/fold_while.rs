@@ -0,0 +1,92 @@
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::Stream;
+use futures_core::task::{Waker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Tells [`FoldWhile`] whether to keep folding or to stop early.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ControlFlow<B, C = ()> {
+    /// Keep pulling items from the stream with the given accumulator.
+    Continue(C),
+    /// Stop and resolve to the given value.
+    Break(B),
+}
+
+/// A future used to fold the items of a stream until the folding closure
+/// asks to stop.
+///
+/// This future is returned by the `Stream::fold_while` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct FoldWhile<St, Fut, T, F> {
+    stream: St,
+    f: F,
+    accum: Option<T>,
+    future: Option<Fut>,
+}
+
+impl<St: Unpin, Fut: Unpin, T, F> Unpin for FoldWhile<St, Fut, T, F> {}
+
+impl<St, Fut, T, F> FoldWhile<St, Fut, T, F>
+where St: Stream,
+      F: FnMut(T, St::Item) -> Fut,
+      Fut: Future<Output = ControlFlow<T, T>>,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(accum: Option<T>);
+    unsafe_pinned!(future: Option<Fut>);
+
+    pub(super) fn new(stream: St, f: F, t: T) -> FoldWhile<St, Fut, T, F> {
+        FoldWhile {
+            stream,
+            f,
+            accum: Some(t),
+            future: None,
+        }
+    }
+}
+
+impl<St, Fut, T, F> FusedFuture for FoldWhile<St, Fut, T, F> {
+    fn is_terminated(&self) -> bool {
+        self.accum.is_none() && self.future.is_none()
+    }
+}
+
+impl<St, Fut, T, F> Future for FoldWhile<St, Fut, T, F>
+    where St: Stream,
+          F: FnMut(T, St::Item) -> Fut,
+          Fut: Future<Output = ControlFlow<T, T>>,
+{
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, waker: &Waker) -> Poll<T> {
+        loop {
+            // we're currently processing a future to produce a new accum value
+            if self.as_mut().accum().is_none() {
+                let flow = ready!(
+                    self.as_mut().future().as_pin_mut()
+                       .expect("FoldWhile polled after completion")
+                       .poll(waker)
+                );
+                self.as_mut().future().set(None);
+                match flow {
+                    ControlFlow::Continue(accum) => *self.as_mut().accum() = Some(accum),
+                    ControlFlow::Break(value) => return Poll::Ready(value),
+                }
+            }
+
+            let item = ready!(self.as_mut().stream().poll_next(waker));
+            let accum = self.as_mut().accum().take()
+                .expect("FoldWhile polled after completion");
+
+            if let Some(e) = item {
+                let future = (self.as_mut().f())(accum, e);
+                self.as_mut().future().set(Some(future));
+            } else {
+                return Poll::Ready(accum)
+            }
+        }
+    }
+}
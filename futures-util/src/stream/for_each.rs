@@ -1,6 +1,6 @@
 use core::pin::Pin;
 use futures_core::future::{FusedFuture, Future};
-use futures_core::stream::{FusedStream, Stream};
+use futures_core::stream::Stream;
 use futures_core::task::{Waker, Poll};
 use pin_utils::{unsafe_pinned, unsafe_unpinned};
 
@@ -14,6 +14,7 @@ pub struct ForEach<St, Fut, F> {
     stream: St,
     f: F,
     future: Option<Fut>,
+    done: bool,
 }
 
 impl<St, Fut, F> Unpin for ForEach<St, Fut, F>
@@ -30,19 +31,21 @@ where St: Stream,
     unsafe_pinned!(stream: St);
     unsafe_unpinned!(f: F);
     unsafe_pinned!(future: Option<Fut>);
+    unsafe_unpinned!(done: bool);
 
     pub(super) fn new(stream: St, f: F) -> ForEach<St, Fut, F> {
         ForEach {
             stream,
             f,
             future: None,
+            done: false,
         }
     }
 }
 
-impl<St: FusedStream, Fut, F> FusedFuture for ForEach<St, Fut, F> {
+impl<St, Fut, F> FusedFuture for ForEach<St, Fut, F> {
     fn is_terminated(&self) -> bool {
-        self.future.is_none() && self.stream.is_terminated()
+        self.done
     }
 }
 
@@ -54,6 +57,7 @@ impl<St, Fut, F> Future for ForEach<St, Fut, F>
     type Output = ();
 
     fn poll(mut self: Pin<&mut Self>, waker: &Waker) -> Poll<()> {
+        assert!(!self.done, "ForEach polled after completion");
         loop {
             if let Some(future) = self.as_mut().future().as_pin_mut() {
                 ready!(future.poll(waker));
@@ -66,6 +70,7 @@ impl<St, Fut, F> Future for ForEach<St, Fut, F>
                     self.as_mut().future().set(Some(future));
                 }
                 None => {
+                    *self.as_mut().done() = true;
                     return Poll::Ready(());
                 }
             }
@@ -0,0 +1,74 @@
+//! Definition of the `FromFn` combinator
+
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Waker, Poll};
+
+/// A stream which produces items by calling a synchronous function.
+///
+/// Created by the `from_fn` function.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct FromFn<F> {
+    f: Option<F>,
+}
+
+impl<F> Unpin for FromFn<F> {}
+
+/// Creates a new stream which yields the items returned by a function.
+///
+/// Every poll of the returned stream calls `f` once and yields its result,
+/// so the stream is never pending. The stream ends the first time `f`
+/// returns `None`, after which `f` is dropped and never called again.
+///
+/// This is the stream counterpart of `std::iter::from_fn`. Use
+/// [`poll_fn`](crate::stream::poll_fn) instead if items are produced
+/// asynchronously.
+///
+/// # Examples
+///
+/// ```
+/// use futures::executor::block_on;
+/// use futures::stream::{self, StreamExt};
+///
+/// let mut count = 3;
+/// let countdown = stream::from_fn(move || {
+///     if count == 0 {
+///         return None;
+///     }
+///     count -= 1;
+///     Some(count)
+/// });
+///
+/// assert_eq!(block_on(countdown.collect::<Vec<_>>()), vec![2, 1, 0]);
+/// ```
+pub fn from_fn<T, F>(f: F) -> FromFn<F>
+where
+    F: FnMut() -> Option<T>,
+{
+    FromFn { f: Some(f) }
+}
+
+impl<F> FusedStream for FromFn<F> {
+    fn is_terminated(&self) -> bool {
+        self.f.is_none()
+    }
+}
+
+impl<T, F> Stream for FromFn<F>
+where
+    F: FnMut() -> Option<T>,
+{
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, _: &Waker) -> Poll<Option<T>> {
+        let item = match self.f.as_mut() {
+            Some(f) => f(),
+            None => return Poll::Ready(None),
+        };
+        if item.is_none() {
+            self.f = None;
+        }
+        Poll::Ready(item)
+    }
+}
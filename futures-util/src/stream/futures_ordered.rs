@@ -84,6 +84,13 @@ impl<T> Future for OrderWrapper<T>
 /// return `Poll::Pending` until the first future completes, even if
 /// some of the later futures have already completed.
 ///
+/// Outputs of futures which complete before the futures preceding them are
+/// buffered until they can be yielded, so a slow future at the front of the
+/// queue can cause an unbounded number of outputs to be held in memory. The
+/// number of buffered outputs can be monitored with
+/// `FuturesOrdered::queued_outputs_len`, and the memory backing them released
+/// with `FuturesOrdered::shrink_to_fit` once they have been yielded.
+///
 /// Note that you can create a ready-made `FuturesOrdered` via the
 /// `futures_ordered` function in the `stream` module, or you can start with an
 /// empty queue with the `FuturesOrdered::new` constructor.
@@ -144,6 +151,21 @@ impl<Fut: Future> FuturesOrdered<Fut> {
         self.in_progress_queue.is_empty() && self.queued_outputs.is_empty()
     }
 
+    /// Returns the number of outputs of completed futures which are waiting
+    /// for earlier futures to complete before they can be yielded.
+    pub fn queued_outputs_len(&self) -> usize {
+        self.queued_outputs.len()
+    }
+
+    /// Shrinks the capacity of the buffer holding the outputs of futures
+    /// which completed out of order as much as possible.
+    ///
+    /// This buffer never shrinks on its own, so this can be used to release
+    /// memory after a burst of out-of-order completions.
+    pub fn shrink_to_fit(&mut self) {
+        self.queued_outputs.shrink_to_fit();
+    }
+
     /// Push a future into the queue.
     ///
     /// This function submits the given future to the internal set for managing.
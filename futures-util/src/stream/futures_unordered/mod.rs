@@ -46,7 +46,8 @@ const TERMINATED_SENTINEL_LENGTH: usize = usize::max_value();
 /// [`FuturesUnordered`] can be filled by [`collect`](Iterator::collect)ing an
 /// iterator of futures into a [`FuturesUnordered`], or by
 /// [`push`](FuturesUnordered::push)ing futures onto an existing
-/// [`FuturesUnordered`]. When new futures are added,
+/// [`FuturesUnordered`], or by [`extend`](Extend::extend)ing it with an
+/// iterator of futures. When new futures are added,
 /// [`poll_next`](Stream::poll_next) must be called in order to begin receiving
 /// wake-ups for new futures.
 ///
@@ -123,6 +124,7 @@ impl<Fut: Future> FuturesUnordered<Fut> {
         let stub_ptr = &*stub as *const Task<Fut>;
         let ready_to_run_queue = Arc::new(ReadyToRunQueue {
             waker: AtomicWaker::new(),
+            woken: AtomicBool::new(false),
             head: AtomicPtr::new(stub_ptr as *mut _),
             tail: UnsafeCell::new(stub_ptr),
             stub,
@@ -293,6 +295,11 @@ impl<Fut: Future> Stream for FuturesUnordered<Fut> {
         // Ensure `parent` is correctly set.
         self.ready_to_run_queue.waker.register(waker);
 
+        // Allow the next wake to reach `waker` again. This has to happen
+        // before the queue is drained so that a future woken while we're
+        // polling isn't left unnoticed.
+        self.ready_to_run_queue.woken.store(false, SeqCst);
+
         loop {
             // Safety: &mut self guarantees the mutual exclusion `dequeue`
             // expects
@@ -469,6 +476,17 @@ impl<Fut: Future> FromIterator<Fut> for FuturesUnordered<Fut> {
     }
 }
 
+impl<Fut: Future> Extend<Fut> for FuturesUnordered<Fut> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = Fut>,
+    {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
 /// Converts a list of futures into a [`Stream`] of outputs from the futures.
 ///
 /// This function will take a list of futures (e.g. a [`Vec`], an [`Iterator`],
@@ -2,7 +2,7 @@ use crate::task::AtomicWaker;
 use std::cell::UnsafeCell;
 use std::ptr;
 use std::sync::Arc;
-use std::sync::atomic::AtomicPtr;
+use std::sync::atomic::{AtomicBool, AtomicPtr};
 use std::sync::atomic::Ordering::{Relaxed, Acquire, Release, AcqRel};
 
 use super::abort::abort;
@@ -18,6 +18,10 @@ pub(super) struct ReadyToRunQueue<Fut> {
     // The waker of the task using `FuturesUnordered`.
     pub(super) waker: AtomicWaker,
 
+    // Whether the waker has been woken since the last call to `poll_next`.
+    // Used to coalesce wakes from several futures into a single one.
+    pub(super) woken: AtomicBool,
+
     // Head/tail of the readiness queue
     pub(super) head: AtomicPtr<Task<Fut>>,
     pub(super) tail: UnsafeCell<*const Task<Fut>>,
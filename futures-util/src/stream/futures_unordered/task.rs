@@ -47,7 +47,9 @@ impl<Fut> ArcWake for Task<Fut> {
         // actual queueing operation, ensuring that we're only queued once.
         //
         // Once the task is inserted call `wake` to notify the parent task,
-        // as it'll want to come along and run our task later.
+        // as it'll want to come along and run our task later. The parent is
+        // only notified once between two calls to `poll_next`, as a single
+        // `poll_next` will run all the tasks enqueued in the meantime.
         //
         // Note that we don't change the reference count of the task here,
         // we merely enqueue the raw pointer. The `FuturesUnordered`
@@ -57,7 +59,9 @@ impl<Fut> ArcWake for Task<Fut> {
         let prev = arc_self.queued.swap(true, SeqCst);
         if !prev {
             inner.enqueue(&**arc_self);
-            inner.waker.wake();
+            if !inner.woken.swap(true, SeqCst) {
+                inner.waker.wake();
+            }
         }
     }
 }
@@ -0,0 +1,89 @@
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Waker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Do something once a stream is done, passing its items on.
+///
+/// This is created by the `Stream::inspect_done` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct InspectDone<St, F>
+    where St: Stream,
+          F: FnOnce(bool),
+{
+    stream: St,
+    f: Option<F>,
+}
+
+impl<St: Stream + Unpin, F: FnOnce(bool)> Unpin for InspectDone<St, F> {}
+
+impl<St, F> InspectDone<St, F>
+    where St: Stream,
+          F: FnOnce(bool),
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: Option<F>);
+
+    pub(super) fn new(stream: St, f: F) -> InspectDone<St, F> {
+        InspectDone { stream, f: Some(f) }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+}
+
+impl<St, F> FusedStream for InspectDone<St, F>
+    where St: Stream,
+          F: FnOnce(bool),
+{
+    fn is_terminated(&self) -> bool {
+        self.f.is_none()
+    }
+}
+
+impl<St, F> Stream for InspectDone<St, F>
+    where St: Stream,
+          F: FnOnce(bool),
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        waker: &Waker
+    ) -> Poll<Option<St::Item>> {
+        if self.f.is_none() {
+            return Poll::Ready(None);
+        }
+        let item = ready!(self.as_mut().stream().poll_next(waker));
+        if item.is_none() {
+            let f = self.as_mut().f().take().unwrap();
+            f(true);
+        }
+        Poll::Ready(item)
+    }
+}
+
+impl<St, F> Drop for InspectDone<St, F>
+    where St: Stream,
+          F: FnOnce(bool),
+{
+    fn drop(&mut self) {
+        // The stream is dropped before it could finish.
+        if let Some(f) = self.f.take() {
+            f(false);
+        }
+    }
+}
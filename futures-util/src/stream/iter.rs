@@ -1,14 +1,17 @@
 use core::pin::Pin;
-use futures_core::stream::Stream;
+use futures_core::stream::{FusedStream, Stream};
 use futures_core::task::{Waker, Poll};
 
 /// A stream which is just a shim over an underlying instance of `Iterator`.
 ///
-/// This stream will never block and is always ready.
+/// This stream will never block and is always ready. Once the iterator has
+/// returned `None`, the stream is terminated and won't call `next` on it
+/// again.
 #[derive(Debug)]
 #[must_use = "streams do nothing unless polled"]
 pub struct Iter<I> {
     iter: I,
+    done: bool,
 }
 
 impl<I> Unpin for Iter<I> {}
@@ -31,6 +34,13 @@ pub fn iter<I>(i: I) -> Iter<I::IntoIter>
 {
     Iter {
         iter: i.into_iter(),
+        done: false,
+    }
+}
+
+impl<I> FusedStream for Iter<I> {
+    fn is_terminated(&self) -> bool {
+        self.done
     }
 }
 
@@ -40,6 +50,13 @@ impl<I> Stream for Iter<I>
     type Item = I::Item;
 
     fn poll_next(mut self: Pin<&mut Self>, _: &Waker) -> Poll<Option<I::Item>> {
-        Poll::Ready(self.iter.next())
+        if self.done {
+            return Poll::Ready(None);
+        }
+        let item = self.iter.next();
+        if item.is_none() {
+            self.done = true;
+        }
+        Poll::Ready(item)
     }
 }
@@ -0,0 +1,80 @@
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Waker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// A stream combinator which applies two closures, one after the other, to
+/// each item of a stream.
+///
+/// This is produced by the `Stream::map_composed` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct MapComposed<St, F, G> {
+    stream: St,
+    f: F,
+    g: G,
+}
+
+impl<St: Unpin, F, G> Unpin for MapComposed<St, F, G> {}
+
+impl<St, T, U, F, G> MapComposed<St, F, G>
+    where St: Stream,
+          F: FnMut(St::Item) -> T,
+          G: FnMut(T) -> U,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(g: G);
+
+    pub(super) fn new(stream: St, f: F, g: G) -> MapComposed<St, F, G> {
+        MapComposed { stream, f, g }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St: FusedStream, F, G> FusedStream for MapComposed<St, F, G> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, T, U, F, G> Stream for MapComposed<St, F, G>
+    where St: Stream,
+          F: FnMut(St::Item) -> T,
+          G: FnMut(T) -> U,
+{
+    type Item = U;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        waker: &Waker
+    ) -> Poll<Option<U>> {
+        let option = ready!(self.as_mut().stream().poll_next(waker));
+        Poll::Ready(option.map(|item| {
+            let item = (self.as_mut().f())(item);
+            (self.as_mut().g())(item)
+        }))
+    }
+}
@@ -0,0 +1,162 @@
+//! The `merge` macro and the round-robin adapters it expands to.
+
+use crate::stream::{StreamExt, Fuse};
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Waker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Merges any number of streams with the same item type into a single stream.
+///
+/// Two streams are merged with [`select`](crate::stream::StreamExt::select).
+/// Three to five streams are merged with [`Merge3`], [`Merge4`] or
+/// [`Merge5`], which poll the streams in round-robin order, so every stream
+/// gets an equal share of the polls instead of the skewed shares produced by
+/// chaining `a.select(b).select(c)`, where the last stream is polled as often
+/// as all of the others combined.
+///
+/// Six or more streams are dealt out alternately into two halves, which are
+/// merged recursively and then combined with `select`, so the shares stay
+/// balanced.
+///
+/// The merged stream completes once all of the input streams have completed.
+///
+/// # Examples
+///
+/// ```
+/// use futures::executor::block_on;
+/// use futures::merge;
+/// use futures::stream::{self, StreamExt};
+///
+/// let a = stream::iter(vec![1, 5]);
+/// let b = stream::iter(vec![2, 6]);
+/// let c = stream::iter(vec![3, 7]);
+/// let d = stream::iter(vec![4, 8]);
+///
+/// let merged = merge!(a, b, c, d);
+/// assert_eq!(vec![1, 2, 3, 4, 5, 6, 7, 8], block_on(merged.collect::<Vec<_>>()));
+/// ```
+#[macro_export]
+macro_rules! merge {
+    (@split [$($left:expr),*] [$($right:expr),*]) => {
+        $crate::stream::StreamExt::select(
+            $crate::merge!($($left),*),
+            $crate::merge!($($right),*),
+        )
+    };
+    (@split [$($left:expr),*] [$($right:expr),*] $a:expr) => {
+        $crate::merge!(@split [$($left,)* $a] [$($right),*])
+    };
+    (@split [$($left:expr),*] [$($right:expr),*] $a:expr, $b:expr $(, $rest:expr)*) => {
+        $crate::merge!(@split [$($left,)* $a] [$($right,)* $b] $($rest),*)
+    };
+    ($stream:expr $(,)*) => {
+        $stream
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::stream::StreamExt::select($a, $b)
+    };
+    ($a:expr, $b:expr, $c:expr $(,)*) => {
+        $crate::stream::Merge3::new($a, $b, $c)
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr $(,)*) => {
+        $crate::stream::Merge4::new($a, $b, $c, $d)
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr $(,)*) => {
+        $crate::stream::Merge5::new($a, $b, $c, $d, $e)
+    };
+    ($($stream:expr),+ $(,)*) => {
+        $crate::merge!(@split [] [] $($stream),+)
+    };
+}
+
+macro_rules! generate {
+    ($(
+        $(#[$doc:meta])*
+        ($Merge:ident, $len:expr, <$(($St:ident, $stream:ident, $idx:tt)),*>),
+    )*) => ($(
+        $(#[$doc])*
+        #[derive(Debug)]
+        #[must_use = "streams do nothing unless polled"]
+        pub struct $Merge<$($St),*> {
+            $($stream: Fuse<$St>,)*
+            next: usize,
+        }
+
+        impl<$($St: Unpin),*> Unpin for $Merge<$($St),*> {}
+
+        impl<Item, $($St: Stream<Item = Item>),*> $Merge<$($St),*> {
+            $(unsafe_pinned!($stream: Fuse<$St>);)*
+            unsafe_unpinned!(next: usize);
+
+            /// Creates a new stream merging the given streams in
+            /// round-robin order.
+            pub fn new($($stream: $St),*) -> $Merge<$($St),*> {
+                $Merge {
+                    $($stream: $stream.fuse(),)*
+                    next: 0,
+                }
+            }
+        }
+
+        impl<Item, $($St: Stream<Item = Item>),*> FusedStream for $Merge<$($St),*> {
+            fn is_terminated(&self) -> bool {
+                $(self.$stream.is_terminated())&&*
+            }
+        }
+
+        impl<Item, $($St: Stream<Item = Item>),*> Stream for $Merge<$($St),*> {
+            type Item = Item;
+
+            fn poll_next(
+                mut self: Pin<&mut Self>,
+                waker: &Waker,
+            ) -> Poll<Option<Item>> {
+                let start = self.next;
+                let mut pending = false;
+                for i in 0..$len {
+                    let idx = (start + i) % $len;
+                    let res = match idx {
+                        $($idx => self.as_mut().$stream().poll_next(waker),)*
+                        _ => unreachable!(),
+                    };
+                    match res {
+                        Poll::Ready(Some(item)) => {
+                            // Start with the following stream next time, so
+                            // that every stream gets its turn.
+                            *self.as_mut().next() = (idx + 1) % $len;
+                            return Poll::Ready(Some(item));
+                        }
+                        Poll::Ready(None) => {}
+                        Poll::Pending => pending = true,
+                    }
+                }
+
+                if pending {
+                    Poll::Pending
+                } else {
+                    Poll::Ready(None)
+                }
+            }
+        }
+    )*)
+}
+
+generate! {
+    /// A stream merging three streams in round-robin order.
+    ///
+    /// This is created by the `merge!` macro.
+    (Merge3, 3, <(St1, stream1, 0), (St2, stream2, 1), (St3, stream3, 2)>),
+
+    /// A stream merging four streams in round-robin order.
+    ///
+    /// This is created by the `merge!` macro.
+    (Merge4, 4, <(St1, stream1, 0), (St2, stream2, 1), (St3, stream3, 2),
+                 (St4, stream4, 3)>),
+
+    /// A stream merging five streams in round-robin order.
+    ///
+    /// This is created by the `merge!` macro.
+    (Merge5, 5, <(St1, stream1, 0), (St2, stream2, 1), (St3, stream3, 2),
+                 (St4, stream4, 3), (St5, stream5, 4)>),
+}
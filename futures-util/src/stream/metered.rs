@@ -0,0 +1,82 @@
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Waker, Poll};
+use pin_utils::unsafe_pinned;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Stream which counts the items passing through it.
+///
+/// This is created by the `Stream::metered` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Metered<St> {
+    stream: St,
+    count: Arc<AtomicUsize>,
+}
+
+impl<St: Unpin> Unpin for Metered<St> {}
+
+impl<St: Stream> Metered<St> {
+    unsafe_pinned!(stream: St);
+
+    pub(super) fn new(stream: St) -> Metered<St> {
+        Metered { stream, count: Arc::new(AtomicUsize::new(0)) }
+    }
+
+    /// Returns a handle to the number of items which have passed through this
+    /// stream so far.
+    ///
+    /// The handle stays valid after the stream has been dropped, and can be
+    /// read from any thread, for example to compute the throughput of the
+    /// stream at whatever interval suits the caller.
+    pub fn counter(&self) -> Arc<AtomicUsize> {
+        self.count.clone()
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St: FusedStream> FusedStream for Metered<St> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St: Stream> Stream for Metered<St> {
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        waker: &Waker
+    ) -> Poll<Option<St::Item>> {
+        let item = ready!(self.as_mut().stream().poll_next(waker));
+        if item.is_some() {
+            // The counter is only ever read, so there is nothing to
+            // synchronize with.
+            self.count.fetch_add(1, Ordering::Relaxed);
+        }
+        Poll::Ready(item)
+    }
+}
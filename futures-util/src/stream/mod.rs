@@ -16,15 +16,27 @@ pub use self::iter::{iter, Iter};
 mod repeat;
 pub use self::repeat::{repeat, Repeat};
 
+mod replicate;
+pub use self::replicate::{replicate, Replicate};
+
 mod chain;
 pub use self::chain::Chain;
 
 mod collect;
 pub use self::collect::Collect;
 
+mod collect_into;
+pub use self::collect_into::CollectInto;
+
 mod concat;
 pub use self::concat::Concat;
 
+mod count_if;
+pub use self::count_if::CountIf;
+
+mod drain;
+pub use self::drain::Drain;
+
 mod empty;
 pub use self::empty::{empty, Empty};
 
@@ -40,6 +52,9 @@ pub use self::flatten::Flatten;
 mod fold;
 pub use self::fold::Fold;
 
+mod fold_while;
+pub use self::fold_while::{ControlFlow, FoldWhile};
+
 mod forward;
 pub use self::forward::Forward;
 
@@ -55,9 +70,19 @@ pub use self::into_future::StreamFuture;
 mod inspect;
 pub use self::inspect::Inspect;
 
+mod inspect_done;
+pub use self::inspect_done::InspectDone;
+
 mod map;
 pub use self::map::Map;
 
+mod map_composed;
+pub use self::map_composed::MapComposed;
+
+// Primary export is a macro
+mod merge;
+pub use self::merge::{Merge3, Merge4, Merge5};
+
 mod next;
 pub use self::next::Next;
 
@@ -73,6 +98,9 @@ pub use self::peek::Peekable;
 mod poll_fn;
 pub use self::poll_fn::{poll_fn, PollFn};
 
+mod from_fn;
+pub use self::from_fn::{from_fn, FromFn};
+
 mod select;
 pub use self::select::Select;
 
@@ -94,9 +122,21 @@ pub use self::then::Then;
 mod unfold;
 pub use self::unfold::{unfold, Unfold};
 
+mod with_context;
+pub use self::with_context::WithContext;
+
+mod with_position;
+pub use self::with_position::{Position, WithPosition};
+
 mod zip;
 pub use self::zip::Zip;
 
+mod zip_n;
+pub use self::zip_n::{Zip3, Zip4};
+
+mod zip_longest;
+pub use self::zip_longest::{EitherOrBoth, ZipLongest};
+
 #[cfg(feature = "std")]
 use std;
 
@@ -110,6 +150,11 @@ mod buffered;
 #[cfg(feature = "std")]
 pub use self::buffered::Buffered;
 
+#[cfg(feature = "std")]
+mod buffered_item;
+#[cfg(feature = "std")]
+pub(crate) use self::buffered_item::{Buffering, poll_buffered};
+
 #[cfg(feature = "std")]
 mod catch_unwind;
 #[cfg(feature = "std")]
@@ -120,6 +165,16 @@ mod chunks;
 #[cfg(feature = "std")]
 pub use self::chunks::Chunks;
 
+#[cfg(feature = "std")]
+mod chunks_exact;
+#[cfg(feature = "std")]
+pub use self::chunks_exact::ChunksExact;
+
+#[cfg(feature = "std")]
+mod flat_map_unordered;
+#[cfg(feature = "std")]
+pub use self::flat_map_unordered::FlatMapUnordered;
+
 #[cfg(feature = "std")]
 mod for_each_concurrent;
 #[cfg(feature = "std")]
@@ -135,16 +190,38 @@ mod futures_unordered;
 #[cfg(feature = "std")]
 pub use self::futures_unordered::{futures_unordered, FuturesUnordered};
 
+#[cfg(feature = "std")]
+mod metered;
+#[cfg(feature = "std")]
+pub use self::metered::Metered;
+
+#[cfg(feature = "std")]
+mod ready_chunks;
+#[cfg(feature = "std")]
+pub use self::ready_chunks::ReadyChunks;
+
 #[cfg(feature = "std")]
 mod split;
 #[cfg(feature = "std")]
 pub use self::split::{SplitStream, SplitSink, ReuniteError};
 
+#[cfg(feature = "std")]
+mod split_results;
+#[cfg(feature = "std")]
+pub use self::split_results::{OkStream, ErrStream};
+
 #[cfg(feature = "std")]
 mod select_all;
 #[cfg(feature = "std")]
 pub use self::select_all::{select_all, SelectAll};
 
+/// An owned, pinned and dynamically typed [`Stream`] which isn't required to
+/// be `Send`.
+///
+/// This is the type returned by [`StreamExt::boxed_local`].
+#[cfg(feature = "std")]
+pub type LocalBoxStream<'a, T> = Pin<Box<dyn Stream<Item = T> + 'a>>;
+
 impl<T: ?Sized> StreamExt for T where T: Stream {}
 
 /// An extension trait for `Stream`s that provides a variety of convenient
@@ -240,6 +317,32 @@ pub trait StreamExt: Stream {
         Map::new(self, f)
     }
 
+    /// Maps this stream's items through two closures applied one after the
+    /// other, returning a new stream of the resulting type.
+    ///
+    /// This is equivalent to `stream.map(f).map(g)`, but both closures are
+    /// run within a single combinator layer, which saves a level of
+    /// [`poll_next`](Stream::poll_next) dispatch per item in hot pipelines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=3);
+    /// let stream = stream.map_composed(|x| x + 3, |x| x * 2);
+    ///
+    /// assert_eq!(vec![8, 10, 12], block_on(stream.collect::<Vec<_>>()));
+    /// ```
+    fn map_composed<T, U, F, G>(self, f: F, g: G) -> MapComposed<Self, F, G>
+        where F: FnMut(Self::Item) -> T,
+              G: FnMut(T) -> U,
+              Self: Sized
+    {
+        MapComposed::new(self, f, g)
+    }
+
     /// Filters the values produced by this stream according to the provided
     /// asynchronous predicate.
     ///
@@ -361,12 +464,64 @@ pub trait StreamExt: Stream {
     /// let output = block_on(rx.collect::<Vec<i32>>());
     /// assert_eq!(output, vec![1, 2, 3, 4, 5]);
     /// ```
+    ///
+    /// Any collection implementing `Default` and `Extend` for the items of
+    /// the stream can be collected into, such as maps, deques or strings:
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    /// use std::collections::{BTreeMap, VecDeque};
+    ///
+    /// let map = block_on(stream::iter(vec![(2, 'b'), (1, 'a')]).collect::<BTreeMap<_, _>>());
+    /// assert_eq!(map.into_iter().collect::<Vec<_>>(), vec![(1, 'a'), (2, 'b')]);
+    ///
+    /// let deque = block_on(stream::iter(1..=3).collect::<VecDeque<_>>());
+    /// assert_eq!(deque, VecDeque::from(vec![1, 2, 3]));
+    ///
+    /// let string = block_on(stream::iter("abc".chars()).collect::<String>());
+    /// assert_eq!(string, "abc");
+    /// ```
     fn collect<C: Default + Extend<Self::Item>>(self) -> Collect<Self, C>
         where Self: Sized
     {
         Collect::new(self)
     }
 
+    /// Transforms a stream into a future which extends an existing
+    /// collection with all of the items of the stream.
+    ///
+    /// This works like [`collect`](StreamExt::collect), but the items are
+    /// added to `collection` in place instead of to a new collection. This
+    /// makes it possible to reuse the same allocation, for example a cleared
+    /// `Vec`, across several streams. The returned future resolves to `()`
+    /// once the stream has ended.
+    ///
+    /// If the future is dropped before completion, the items which have been
+    /// pulled out of the stream so far are kept in `collection`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut buf = Vec::with_capacity(3);
+    ///
+    /// block_on(stream::iter(1..=3).collect_into(&mut buf));
+    /// assert_eq!(buf, vec![1, 2, 3]);
+    ///
+    /// buf.clear();
+    /// block_on(stream::iter(4..=5).collect_into(&mut buf));
+    /// assert_eq!(buf, vec![4, 5]);
+    /// ```
+    fn collect_into<C>(self, collection: &mut C) -> CollectInto<'_, Self, C>
+        where C: Extend<Self::Item> + ?Sized,
+              Self: Sized,
+    {
+        CollectInto::new(self, collection)
+    }
+
     /// Concatenate all items of a stream into a single extendable
     /// destination, returning a future representing the end result.
     ///
@@ -406,6 +561,31 @@ pub trait StreamExt: Stream {
         Concat::new(self)
     }
 
+    /// Counts the items of this stream for which the provided predicate
+    /// returns `true`.
+    ///
+    /// The returned future drives the stream to completion and resolves to
+    /// the number of matching items. This is equivalent to filtering the
+    /// stream and counting the remaining items, without the intermediate
+    /// adapter and the future created per item by
+    /// [`filter`](StreamExt::filter).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let evens = stream::iter(1..=10).count_if(|x| x % 2 == 0);
+    /// assert_eq!(block_on(evens), 5);
+    /// ```
+    fn count_if<F>(self, f: F) -> CountIf<Self, F>
+        where F: FnMut(&Self::Item) -> bool,
+              Self: Sized
+    {
+        CountIf::new(self, f)
+    }
+
     /// Execute an accumulating asynchronous computation over a stream,
     /// collecting all the values into one final result.
     ///
@@ -434,6 +614,42 @@ pub trait StreamExt: Stream {
         Fold::new(self, f, init)
     }
 
+    /// Execute an accumulating asynchronous computation over a stream, with
+    /// the option of stopping before the stream is exhausted.
+    ///
+    /// This combinator behaves like [`fold`](StreamExt::fold), except that
+    /// the closure returns a future resolving to a [`ControlFlow`]. With
+    /// `ControlFlow::Continue(acc)` folding carries on with `acc` as the new
+    /// accumulator, while `ControlFlow::Break(value)` stops pulling items from
+    /// the stream and makes the returned future resolve to `value`. If the
+    /// stream is exhausted first, the future resolves to the last accumulator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::future;
+    /// use futures::stream::{self, ControlFlow, StreamExt};
+    ///
+    /// let number_stream = stream::iter(1..);
+    /// let sum = number_stream.fold_while(0, |acc, x| {
+    ///     let acc = acc + x;
+    ///     future::ready(if acc > 10 {
+    ///         ControlFlow::Break(acc)
+    ///     } else {
+    ///         ControlFlow::Continue(acc)
+    ///     })
+    /// });
+    /// assert_eq!(block_on(sum), 15);
+    /// ```
+    fn fold_while<T, Fut, F>(self, init: T, f: F) -> FoldWhile<Self, Fut, T, F>
+        where F: FnMut(T, Self::Item) -> Fut,
+              Fut: Future<Output = ControlFlow<T, T>>,
+              Self: Sized
+    {
+        FoldWhile::new(self, f, init)
+    }
+
     /// Flattens a stream of streams into just one continuous stream.
     ///
     /// # Examples
@@ -471,6 +687,70 @@ pub trait StreamExt: Stream {
         Flatten::new(self)
     }
 
+    /// Maps a stream like [`StreamExt::map`] but flattens the resulting
+    /// sub-streams concurrently, yielding their items as they become
+    /// available.
+    ///
+    /// The closure `f` is called for each item of this stream and must return
+    /// a stream. Items from all of the active sub-streams are interleaved in
+    /// the order in which they become ready, so the relative order of items
+    /// from different sub-streams is not preserved.
+    ///
+    /// The first argument is an optional limit on the number of concurrently
+    /// active sub-streams. If this limit is not `None`, no more than `limit`
+    /// sub-streams will be polled at once and the next item of this stream
+    /// will only be pulled once one of them has finished. The `limit` argument
+    /// is of type `Into<Option<usize>>`, and so can be provided as either
+    /// `None`, `Some(10)`, or just `10`. Note: a limit of zero is interpreted
+    /// as no limit at all, and will have the same result as passing in `None`.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![1, 4, 7]);
+    /// let stream = stream.flat_map_unordered(None, |x| stream::iter(x..x + 3));
+    ///
+    /// let mut values = block_on(stream.collect::<Vec<_>>());
+    /// values.sort();
+    /// assert_eq!(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], values);
+    /// ```
+    ///
+    /// The sub-streams have to be `Unpin`. Sub-streams which aren't, such as
+    /// ones built from `async` blocks, can be pinned with `Box::pin` first:
+    ///
+    /// ```
+    /// #![feature(async_await, futures_api)]
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![1, 2, 3]);
+    /// let stream = stream.flat_map_unordered(None, |x| {
+    ///     Box::pin(stream::once(async move { x * 2 }))
+    /// });
+    ///
+    /// let mut values = block_on(stream.collect::<Vec<_>>());
+    /// values.sort();
+    /// assert_eq!(vec![2, 4, 6], values);
+    /// ```
+    #[cfg(feature = "std")]
+    fn flat_map_unordered<U, F>(
+        self,
+        limit: impl Into<Option<usize>>,
+        f: F,
+    ) -> FlatMapUnordered<Self, U, F>
+        where F: FnMut(Self::Item) -> U,
+              U: Stream + Unpin,
+              Self: Sized,
+    {
+        FlatMapUnordered::new(self, limit.into(), f)
+    }
+
     /// Skip elements on this stream while the provided asynchronous predicate
     /// resolves to `true`.
     ///
@@ -568,6 +848,29 @@ pub trait StreamExt: Stream {
         ForEach::new(self, f)
     }
 
+    /// Runs this stream to completion, discarding all of its items.
+    ///
+    /// The returned future resolves to `()` once the stream has ended. This
+    /// is useful for streams which are only run for their side effects, and
+    /// avoids the closure and per-item future of an equivalent
+    /// [`for_each`](StreamExt::for_each).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut x = 0;
+    /// block_on(stream::iter(1..=3).inspect(|item| x += item).drain());
+    /// assert_eq!(x, 6);
+    /// ```
+    fn drain(self) -> Drain<Self>
+        where Self: Sized
+    {
+        Drain::new(self)
+    }
+
     /// Runs this stream to completion, executing the provided asynchronous
     /// closure for each element on the stream concurrently as elements become
     /// available.
@@ -798,13 +1101,43 @@ pub trait StreamExt: Stream {
         Box::pin(self)
     }
 
+    /// Wrap the stream in a Box, pinning it and erasing its type.
+    ///
+    /// Unlike [`StreamExt::boxed`], the concrete type of the stream is
+    /// hidden behind a [`LocalBoxStream`] trait object. The stream isn't
+    /// required to be `Send`, which makes this suitable for streams driven by
+    /// single-threaded executors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, LocalBoxStream, StreamExt};
+    /// use std::rc::Rc;
+    ///
+    /// fn numbers() -> LocalBoxStream<'static, Rc<u32>> {
+    ///     stream::iter(1..=3).map(Rc::new).boxed_local()
+    /// }
+    ///
+    /// let vec = block_on(numbers().map(|x| *x).collect::<Vec<_>>());
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn boxed_local<'a>(self) -> LocalBoxStream<'a, Self::Item>
+        where Self: Sized + 'a
+    {
+        Box::pin(self)
+    }
+
     /// An adaptor for creating a buffered list of pending futures.
     ///
     /// If this stream's item can be converted into a future, then this adaptor
     /// will buffer up to at most `n` futures and then return the outputs in the
     /// same order as the underlying stream. No more than `n` futures will be
     /// buffered at any point in time, and less than `n` may also be buffered
-    /// depending on the state of each future.
+    /// depending on the state of each future. If `n` is `0`, the number of
+    /// buffered futures is unbounded, like with a `limit` of `None` or `0`
+    /// for [`for_each_concurrent`](StreamExt::for_each_concurrent).
     ///
     /// The returned stream will be a stream of each future's output.
     ///
@@ -824,7 +1157,9 @@ pub trait StreamExt: Stream {
     /// will buffer up to `n` futures and then return the outputs in the order
     /// in which they complete. No more than `n` futures will be buffered at
     /// any point in time, and less than `n` may also be buffered depending on
-    /// the state of each future.
+    /// the state of each future. If `n` is `0`, the number of buffered futures
+    /// is unbounded, like with a `limit` of `None` or `0` for
+    /// [`for_each_concurrent`](StreamExt::for_each_concurrent).
     ///
     /// The returned stream will be a stream of each future's output.
     ///
@@ -889,6 +1224,101 @@ pub trait StreamExt: Stream {
         Zip::new(self, other)
     }
 
+    /// An adapter for zipping three streams together.
+    ///
+    /// This works like [`zip`](StreamExt::zip), but yields flat tuples of
+    /// three items instead of nesting them as `stream1.zip(stream2).zip(stream3)`
+    /// would. The zipped stream ends as soon as any of the streams ends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream1 = stream::iter(1..=3);
+    /// let stream2 = stream::iter(5..=10);
+    /// let stream3 = stream::iter(vec!['a', 'b']);
+    ///
+    /// let vec = block_on(stream1.zip3(stream2, stream3)
+    ///                           .collect::<Vec<_>>());
+    /// assert_eq!(vec![(1, 5, 'a'), (2, 6, 'b')], vec);
+    /// ```
+    fn zip3<St2, St3>(self, stream2: St2, stream3: St3) -> Zip3<Self, St2, St3>
+        where St2: Stream,
+              St3: Stream,
+              Self: Sized,
+    {
+        Zip3::new(self, stream2, stream3)
+    }
+
+    /// An adapter for zipping four streams together.
+    ///
+    /// This is the same as [`zip3`](StreamExt::zip3), with four streams.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream1 = stream::iter(1..=3);
+    /// let stream2 = stream::iter(5..=10);
+    /// let stream3 = stream::iter(vec!['a', 'b', 'c']);
+    /// let stream4 = stream::iter(vec![true, false, true, false]);
+    ///
+    /// let vec = block_on(stream1.zip4(stream2, stream3, stream4)
+    ///                           .collect::<Vec<_>>());
+    /// assert_eq!(vec![(1, 5, 'a', true), (2, 6, 'b', false), (3, 7, 'c', true)], vec);
+    /// ```
+    fn zip4<St2, St3, St4>(
+        self,
+        stream2: St2,
+        stream3: St3,
+        stream4: St4,
+    ) -> Zip4<Self, St2, St3, St4>
+        where St2: Stream,
+              St3: Stream,
+              St4: Stream,
+              Self: Sized,
+    {
+        Zip4::new(self, stream2, stream3, stream4)
+    }
+
+    /// An adapter for zipping two streams together until both of them have
+    /// ended.
+    ///
+    /// Unlike [`zip`](StreamExt::zip), which ends as soon as either stream
+    /// ends, the zipped stream keeps going until both streams are exhausted.
+    /// Each item is an [`EitherOrBoth`]: [`Both`](EitherOrBoth::Both) while
+    /// both streams produce items, and then [`Left`](EitherOrBoth::Left) or
+    /// [`Right`](EitherOrBoth::Right) with the items of whichever stream is
+    /// longer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, EitherOrBoth, StreamExt};
+    ///
+    /// let stream1 = stream::iter(1..=3);
+    /// let stream2 = stream::iter(5..=6);
+    ///
+    /// let vec = block_on(stream1.zip_longest(stream2)
+    ///                           .collect::<Vec<_>>());
+    /// assert_eq!(vec![
+    ///     EitherOrBoth::Both(1, 5),
+    ///     EitherOrBoth::Both(2, 6),
+    ///     EitherOrBoth::Left(3),
+    /// ], vec);
+    /// ```
+    fn zip_longest<St>(self, other: St) -> ZipLongest<Self, St>
+        where St: Stream,
+              Self: Sized,
+    {
+        ZipLongest::new(self, other)
+    }
+
     /// Adapter for chaining two stream.
     ///
     /// The resulting stream emits elements from the first stream, and when
@@ -952,6 +1382,80 @@ pub trait StreamExt: Stream {
         Chunks::new(self, capacity)
     }
 
+    /// An adaptor for chunking up items of the stream inside vectors of
+    /// exactly `capacity` items.
+    ///
+    /// This works like [`chunks`](StreamExt::chunks), except that a partial
+    /// chunk is never yielded. If the underlying stream ends before the last
+    /// chunk is full, the leftover items are kept and can be retrieved with
+    /// [`ChunksExact::into_remainder`] once the returned stream has
+    /// terminated. This mirrors `slice::chunks_exact`.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut stream = stream::iter(1..=7).chunks_exact(3);
+    ///
+    /// let chunks = block_on(stream.by_ref().collect::<Vec<_>>());
+    /// assert_eq!(vec![vec![1, 2, 3], vec![4, 5, 6]], chunks);
+    /// assert_eq!(vec![7], stream.into_remainder());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `capacity` is zero.
+    #[cfg(feature = "std")]
+    fn chunks_exact(self, capacity: usize) -> ChunksExact<Self>
+        where Self: Sized
+    {
+        ChunksExact::new(self, capacity)
+    }
+
+    /// An adaptor for chunking up the items of the stream which are ready
+    /// inside vectors of at most `capacity` items.
+    ///
+    /// Unlike [`chunks`](StreamExt::chunks), this adaptor never waits for a
+    /// chunk to fill up: as soon as the underlying stream returns `Pending`,
+    /// the items collected so far are yielded as a (possibly partial) chunk.
+    /// The returned stream is only `Pending` if no items are ready at all.
+    /// When the underlying stream ends, any remaining items are yielded as a
+    /// final partial chunk.
+    ///
+    /// This is useful for batching up work without adding latency, for
+    /// example to write out all the messages which are already available
+    /// with a single system call.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=5).ready_chunks(2);
+    ///
+    /// let chunks = block_on(stream.collect::<Vec<_>>());
+    /// assert_eq!(vec![vec![1, 2], vec![3, 4], vec![5]], chunks);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `capacity` is zero.
+    #[cfg(feature = "std")]
+    fn ready_chunks(self, capacity: usize) -> ReadyChunks<Self>
+        where Self: Sized
+    {
+        ReadyChunks::new(self, capacity)
+    }
+
     /// This combinator will attempt to pull items from both streams. Each
     /// stream will be polled in a round-robin fashion, and whenever a stream is
     /// ready to yield an item that item is yielded.
@@ -1006,6 +1510,37 @@ pub trait StreamExt: Stream {
         split::split(self)
     }
 
+    /// Splits a stream of `Result`s into a stream of the successful values and
+    /// a stream of the errors.
+    ///
+    /// Both halves share the underlying stream. Whenever one half pulls an
+    /// item that belongs to the other half, the item is buffered until the
+    /// other half is polled for it. Note that this buffer is unbounded: if one
+    /// of the halves is never polled (or dropped), every item destined for it
+    /// will be kept in memory for as long as the other half keeps going.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Err("a"), Ok(2), Err("b")]);
+    /// let (oks, errs) = stream.split_results();
+    ///
+    /// assert_eq!(vec![1, 2], block_on(oks.collect::<Vec<_>>()));
+    /// assert_eq!(vec!["a", "b"], block_on(errs.collect::<Vec<_>>()));
+    /// ```
+    #[cfg(feature = "std")]
+    fn split_results<T, E>(self) -> (OkStream<Self>, ErrStream<Self>)
+        where Self: Stream<Item = Result<T, E>> + Sized
+    {
+        split_results::split_results(self)
+    }
+
     /// Do something with each item of this stream, afterwards passing it on.
     ///
     /// This is similar to the `Iterator::inspect` method in the standard
@@ -1018,6 +1553,111 @@ pub trait StreamExt: Stream {
         Inspect::new(self, f)
     }
 
+    /// Count the items passing through this stream.
+    ///
+    /// The number of items yielded so far can be read through the handle
+    /// returned by [`Metered::counter`], which makes it possible to measure
+    /// the throughput of a stream without depending on a particular timer.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let stream = stream::iter(1..=3).metered();
+    /// let counter = stream.counter();
+    ///
+    /// assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![1, 2, 3]);
+    /// assert_eq!(counter.load(Ordering::Relaxed), 3);
+    /// ```
+    #[cfg(feature = "std")]
+    fn metered(self) -> Metered<Self>
+        where Self: Sized
+    {
+        Metered::new(self)
+    }
+
+    /// Do something once this stream is done, passing its items on
+    /// unchanged.
+    ///
+    /// The closure `f` is called exactly once: with `true` when the stream
+    /// ends normally by yielding `None`, or with `false` if the returned
+    /// stream is dropped before that happens. This makes it easy to release
+    /// resources or record statistics tied to the lifetime of a stream.
+    ///
+    /// The returned stream stops polling the underlying stream once it has
+    /// yielded `None`, and implements [`FusedStream`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut finished = None;
+    /// let stream = stream::iter(1..=3).inspect_done(|done| finished = Some(done));
+    ///
+    /// assert_eq!(vec![1, 2, 3], block_on(stream.collect::<Vec<_>>()));
+    /// assert_eq!(finished, Some(true));
+    /// ```
+    fn inspect_done<F>(self, f: F) -> InspectDone<Self, F>
+        where F: FnOnce(bool),
+              Self: Sized,
+    {
+        InspectDone::new(self, f)
+    }
+
+    /// Enters a context around every poll of this stream.
+    ///
+    /// Before each call to [`poll_next`](Stream::poll_next) on the underlying
+    /// stream, `enter` is called to create a guard value, which is dropped
+    /// right after the underlying stream has been polled. This is the stream
+    /// counterpart of
+    /// [`FutureExt::with_context`](crate::future::FutureExt::with_context).
+    fn with_context<C, F>(self, enter: F) -> WithContext<Self, F>
+        where F: FnMut() -> C,
+              Self: Sized,
+    {
+        WithContext::new(self, enter)
+    }
+
+    /// Yields each item of this stream along with its [`Position`] in the
+    /// stream.
+    ///
+    /// The position tells whether an item is the [`First`](Position::First),
+    /// a [`Middle`](Position::Middle) or the [`Last`](Position::Last) one, or
+    /// the [`Only`](Position::Only) item of the stream, which is handy for
+    /// things like joining items with separators. To know whether an item is
+    /// the last one, the returned stream has to look one item ahead, so each
+    /// item is only yielded once the next one (or the end of the stream) is
+    /// available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, Position, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=3);
+    ///
+    /// let vec = block_on(stream.with_position().collect::<Vec<_>>());
+    /// assert_eq!(vec![
+    ///     (Position::First, 1),
+    ///     (Position::Middle, 2),
+    ///     (Position::Last, 3),
+    /// ], vec);
+    /// ```
+    fn with_position(self) -> WithPosition<Self>
+        where Self: Sized,
+    {
+        WithPosition::new(self)
+    }
+
     /// Wrap this stream in an `Either` stream, making it the left-hand variant
     /// of that `Either`.
     ///
@@ -0,0 +1,117 @@
+use crate::stream::Fuse;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Waker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use std::mem;
+use std::pin::Pin;
+use std::prelude::v1::*;
+
+/// An adaptor that chunks up all the items that are ready in vectors of at
+/// most a given size.
+///
+/// This adaptor never waits for more items to fill up a chunk: as soon as the
+/// underlying stream returns `Pending`, the items buffered so far are passed
+/// on. This is created by the `Stream::ready_chunks` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct ReadyChunks<St: Stream> {
+    stream: Fuse<St>,
+    items: Vec<St::Item>,
+    cap: usize,
+}
+
+impl<St: Unpin + Stream> Unpin for ReadyChunks<St> {}
+
+impl<St: Stream> ReadyChunks<St> where St: Stream {
+    unsafe_unpinned!(items:  Vec<St::Item>);
+    unsafe_pinned!(stream: Fuse<St>);
+
+    pub(super) fn new(stream: St, capacity: usize) -> ReadyChunks<St> {
+        assert!(capacity > 0);
+
+        ReadyChunks {
+            stream: super::Fuse::new(stream),
+            items: Vec::with_capacity(capacity),
+            cap: capacity,
+        }
+    }
+
+    fn take(mut self: Pin<&mut Self>) -> Vec<St::Item> {
+        let cap = self.cap;
+        mem::replace(self.as_mut().items(), Vec::with_capacity(cap))
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.stream.get_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream.into_inner()
+    }
+}
+
+impl<St: Stream> FusedStream for ReadyChunks<St> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated() && self.items.is_empty()
+    }
+}
+
+impl<St: Stream> Stream for ReadyChunks<St> {
+    type Item = Vec<St::Item>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        waker: &Waker,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            match self.as_mut().stream().poll_next(waker) {
+                // Only wait for more items if we don't have any to hand out
+                // yet.
+                Poll::Pending => {
+                    return if self.items.is_empty() {
+                        Poll::Pending
+                    } else {
+                        Poll::Ready(Some(self.as_mut().take()))
+                    }
+                }
+
+                // Push the item into the buffer and check whether it is full.
+                // If so, replace our buffer with a new and empty one and return
+                // the full one.
+                Poll::Ready(Some(item)) => {
+                    self.as_mut().items().push(item);
+                    if self.items.len() >= self.cap {
+                        return Poll::Ready(Some(self.as_mut().take()))
+                    }
+                }
+
+                // Since the underlying stream ran out of values, return what we
+                // have buffered, if we have anything.
+                Poll::Ready(None) => {
+                    let last = if self.items.is_empty() {
+                        None
+                    } else {
+                        Some(self.as_mut().take())
+                    };
+
+                    return Poll::Ready(last)
+                }
+            }
+        }
+    }
+}
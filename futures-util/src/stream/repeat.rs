@@ -1,5 +1,5 @@
 use core::pin::Pin;
-use futures_core::stream::Stream;
+use futures_core::stream::{FusedStream, Stream};
 use futures_core::task::{Waker, Poll};
 
 /// Stream that produces the same element repeatedly.
@@ -32,6 +32,13 @@ pub fn repeat<T>(item: T) -> Repeat<T>
 
 impl<T> Unpin for Repeat<T> {}
 
+impl<T> FusedStream for Repeat<T> {
+    fn is_terminated(&self) -> bool {
+        // The stream never terminates
+        false
+    }
+}
+
 impl<T> Stream for Repeat<T>
     where T: Clone
 {
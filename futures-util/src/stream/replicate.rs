@@ -0,0 +1,70 @@
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Waker, Poll};
+
+/// Stream that produces the same element a given number of times.
+///
+/// This structure is created by the `stream::replicate` function.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Replicate<T> {
+    item: Option<T>,
+    remaining: usize,
+}
+
+/// Create a stream which produces the same item `count` times.
+///
+/// This is equivalent to `stream::repeat(item).take(count)`, except that the
+/// last item is moved out of the stream instead of being cloned, and that the
+/// number of remaining items is always known, see
+/// [`Replicate::size_hint`].
+///
+/// ```
+/// use futures::executor::block_on;
+/// use futures::stream::{self, StreamExt};
+///
+/// let stream = stream::replicate(9, 3);
+/// assert_eq!(vec![9, 9, 9], block_on(stream.collect::<Vec<i32>>()));
+/// ```
+pub fn replicate<T>(item: T, count: usize) -> Replicate<T>
+    where T: Clone
+{
+    Replicate {
+        item: if count == 0 { None } else { Some(item) },
+        remaining: count,
+    }
+}
+
+impl<T> Replicate<T> {
+    /// Returns the bounds on the number of items this stream still produces,
+    /// in the same format as `Iterator::size_hint`.
+    ///
+    /// Both bounds are always equal to the number of remaining items.
+    pub fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> Unpin for Replicate<T> {}
+
+impl<T> FusedStream for Replicate<T> {
+    fn is_terminated(&self) -> bool {
+        self.remaining == 0
+    }
+}
+
+impl<T> Stream for Replicate<T>
+    where T: Clone
+{
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, _: &Waker) -> Poll<Option<Self::Item>> {
+        let item = match self.remaining {
+            0 => None,
+            1 => self.item.take(),
+            _ => self.item.clone(),
+        };
+        self.remaining = self.remaining.saturating_sub(1);
+        Poll::Ready(item)
+    }
+}
@@ -72,6 +72,11 @@ fn poll_inner<St1, St2>(
 ) -> Poll<Option<St1::Item>>
     where St1: Stream, St2: Stream<Item = St1::Item>
 {
+    // Whichever stream yields an item, the other one gets polled first next
+    // time. `flag` only needs to be flipped when `a` yields: if `b` yields,
+    // `a` is already the one going first, no matter how many times it has
+    // been `Pending` in between. This way an always-ready stream can't
+    // starve the other one.
     let a_done = match a.poll_next(waker) {
         Poll::Ready(Some(item)) => {
             // give the other stream a chance to go first next time
@@ -0,0 +1,175 @@
+use futures_core::stream::{Stream, TryStream};
+use futures_core::task::{Waker, Poll};
+use pin_utils::unsafe_unpinned;
+use std::collections::VecDeque;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::lock::BiLock;
+use crate::task::{ArcWake, AtomicWaker, waker_ref};
+
+/// The wakers of both halves returned by `split_results`.
+///
+/// The underlying stream is polled with a waker waking both halves, as it
+/// only keeps the waker it was last polled with, and either half may need to
+/// pick up the next item.
+#[derive(Debug)]
+struct SplitWakers {
+    ok: AtomicWaker,
+    err: AtomicWaker,
+}
+
+impl ArcWake for SplitWakers {
+    fn wake(arc_self: &Arc<Self>) {
+        arc_self.ok.wake();
+        arc_self.err.wake();
+    }
+}
+
+/// State shared between the two halves returned by `split_results`.
+#[derive(Debug)]
+struct Shared<St: TryStream> {
+    stream: St,
+    done: bool,
+    oks: VecDeque<St::Ok>,
+    errs: VecDeque<St::Error>,
+    wakers: Arc<SplitWakers>,
+}
+
+impl<St: TryStream> Shared<St> {
+    unsafe_unpinned!(done: bool);
+    unsafe_unpinned!(oks: VecDeque<St::Ok>);
+    unsafe_unpinned!(errs: VecDeque<St::Error>);
+
+    fn poll_stream(
+        self: Pin<&mut Self>,
+    ) -> Poll<Option<Result<St::Ok, St::Error>>> {
+        // Safe to call `get_unchecked_mut` because we won't move the stream.
+        let this = unsafe { Pin::get_unchecked_mut(self) };
+        let waker = waker_ref(&this.wakers);
+        unsafe { Pin::new_unchecked(&mut this.stream) }.try_poll_next(&waker)
+    }
+
+    fn poll_next_ok(mut self: Pin<&mut Self>, waker: &Waker) -> Poll<Option<St::Ok>> {
+        self.wakers.ok.register(waker);
+        if let Some(item) = self.as_mut().oks().pop_front() {
+            return Poll::Ready(Some(item));
+        }
+        while !self.done {
+            match self.as_mut().poll_stream() {
+                Poll::Ready(Some(Ok(item))) => return Poll::Ready(Some(item)),
+                Poll::Ready(Some(Err(e))) => {
+                    // Stash the error for the other half and let it know.
+                    self.as_mut().errs().push_back(e);
+                    self.wakers.err.wake();
+                }
+                Poll::Ready(None) => {
+                    *self.as_mut().done() = true;
+                    self.wakers.err.wake();
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        Poll::Ready(None)
+    }
+
+    fn poll_next_err(mut self: Pin<&mut Self>, waker: &Waker) -> Poll<Option<St::Error>> {
+        self.wakers.err.register(waker);
+        if let Some(e) = self.as_mut().errs().pop_front() {
+            return Poll::Ready(Some(e));
+        }
+        while !self.done {
+            match self.as_mut().poll_stream() {
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(e)),
+                Poll::Ready(Some(Ok(item))) => {
+                    // Stash the item for the other half and let it know.
+                    self.as_mut().oks().push_back(item);
+                    self.wakers.ok.wake();
+                }
+                Poll::Ready(None) => {
+                    *self.as_mut().done() = true;
+                    self.wakers.ok.wake();
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        Poll::Ready(None)
+    }
+}
+
+/// The stream of successful values returned by
+/// [`StreamExt::split_results`](super::StreamExt::split_results).
+#[must_use = "streams do nothing unless polled"]
+pub struct OkStream<St: TryStream>(BiLock<Shared<St>>);
+
+impl<St: TryStream> Unpin for OkStream<St> {}
+
+impl<St> fmt::Debug for OkStream<St>
+where
+    St: TryStream + fmt::Debug,
+    St::Ok: fmt::Debug,
+    St::Error: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple("OkStream")
+            .field(&self.0)
+            .finish()
+    }
+}
+
+impl<St: TryStream> Stream for OkStream<St> {
+    type Item = St::Ok;
+
+    fn poll_next(self: Pin<&mut Self>, waker: &Waker) -> Poll<Option<St::Ok>> {
+        match self.0.poll_lock(waker) {
+            Poll::Ready(mut inner) => inner.as_pin_mut().poll_next_ok(waker),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// The stream of errors returned by
+/// [`StreamExt::split_results`](super::StreamExt::split_results).
+#[must_use = "streams do nothing unless polled"]
+pub struct ErrStream<St: TryStream>(BiLock<Shared<St>>);
+
+impl<St: TryStream> Unpin for ErrStream<St> {}
+
+impl<St> fmt::Debug for ErrStream<St>
+where
+    St: TryStream + fmt::Debug,
+    St::Ok: fmt::Debug,
+    St::Error: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple("ErrStream")
+            .field(&self.0)
+            .finish()
+    }
+}
+
+impl<St: TryStream> Stream for ErrStream<St> {
+    type Item = St::Error;
+
+    fn poll_next(self: Pin<&mut Self>, waker: &Waker) -> Poll<Option<St::Error>> {
+        match self.0.poll_lock(waker) {
+            Poll::Ready(mut inner) => inner.as_pin_mut().poll_next_err(waker),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+pub fn split_results<St: TryStream>(stream: St) -> (OkStream<St>, ErrStream<St>) {
+    let (a, b) = BiLock::new(Shared {
+        stream,
+        done: false,
+        oks: VecDeque::new(),
+        errs: VecDeque::new(),
+        wakers: Arc::new(SplitWakers {
+            ok: AtomicWaker::new(),
+            err: AtomicWaker::new(),
+        }),
+    });
+    (OkStream(a), ErrStream(b))
+}
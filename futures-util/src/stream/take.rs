@@ -1,5 +1,5 @@
 use core::pin::Pin;
-use futures_core::stream::Stream;
+use futures_core::stream::{FusedStream, Stream};
 use futures_core::task::{Waker, Poll};
 use pin_utils::{unsafe_pinned, unsafe_unpinned};
 
@@ -43,6 +43,10 @@ impl<St: Stream> Take<St> {
 
     /// Consumes this combinator, returning the underlying stream.
     ///
+    /// The underlying stream is never polled again once the limit has been
+    /// reached, so after `n` items have been yielded the returned stream is
+    /// positioned right after the `n`th item.
+    ///
     /// Note that this may discard intermediate state of this combinator, so
     /// care should be taken to avoid losing resources when this is called.
     pub fn into_inner(self) -> St {
@@ -50,6 +54,13 @@ impl<St: Stream> Take<St> {
     }
 }
 
+impl<St: Stream> FusedStream for Take<St> {
+    fn is_terminated(&self) -> bool {
+        // `remaining` is also zeroed when the underlying stream ends early.
+        self.remaining == 0
+    }
+}
+
 impl<St> Stream for Take<St>
     where St: Stream,
 {
@@ -0,0 +1,70 @@
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Waker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`with_context`](super::StreamExt::with_context) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct WithContext<St, F> {
+    stream: St,
+    enter: F,
+}
+
+impl<St: Unpin, F> Unpin for WithContext<St, F> {}
+
+impl<St, F, C> WithContext<St, F>
+    where St: Stream,
+          F: FnMut() -> C,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(enter: F);
+
+    pub(super) fn new(stream: St, enter: F) -> WithContext<St, F> {
+        WithContext { stream, enter }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St: FusedStream, F> FusedStream for WithContext<St, F> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, F, C> Stream for WithContext<St, F>
+    where St: Stream,
+          F: FnMut() -> C,
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        waker: &Waker
+    ) -> Poll<Option<St::Item>> {
+        let _guard = (self.as_mut().enter())();
+        self.as_mut().stream().poll_next(waker)
+    }
+}
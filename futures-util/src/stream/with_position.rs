@@ -0,0 +1,115 @@
+use crate::stream::{StreamExt, Fuse};
+use core::mem;
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Waker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// The position of an item yielded by [`WithPosition`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Position {
+    /// The first item of a stream with more than one item.
+    First,
+    /// An item which is neither the first nor the last one.
+    Middle,
+    /// The last item of a stream with more than one item.
+    Last,
+    /// The only item of a stream.
+    Only,
+}
+
+/// A stream which yields each item of the underlying stream along with its
+/// [`Position`].
+///
+/// This is produced by the `Stream::with_position` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct WithPosition<St: Stream> {
+    stream: Fuse<St>,
+    peeked: Option<St::Item>,
+    started: bool,
+}
+
+impl<St: Stream + Unpin> Unpin for WithPosition<St> {}
+
+impl<St: Stream> WithPosition<St> {
+    unsafe_pinned!(stream: Fuse<St>);
+    unsafe_unpinned!(peeked: Option<St::Item>);
+    unsafe_unpinned!(started: bool);
+
+    pub(super) fn new(stream: St) -> WithPosition<St> {
+        WithPosition {
+            stream: stream.fuse(),
+            peeked: None,
+            started: false,
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.stream.get_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream.into_inner()
+    }
+}
+
+impl<St: Stream> FusedStream for WithPosition<St> {
+    fn is_terminated(&self) -> bool {
+        self.peeked.is_none() && self.stream.is_terminated()
+    }
+}
+
+impl<St: Stream> Stream for WithPosition<St> {
+    type Item = (Position, St::Item);
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        waker: &Waker
+    ) -> Poll<Option<Self::Item>> {
+        let item = match self.as_mut().peeked().take() {
+            Some(item) => item,
+            None => match ready!(self.as_mut().stream().poll_next(waker)) {
+                Some(item) => item,
+                None => return Poll::Ready(None),
+            },
+        };
+
+        // Look one item ahead to find out whether `item` is the last one.
+        let has_next = match self.as_mut().stream().poll_next(waker) {
+            Poll::Ready(Some(next)) => {
+                *self.as_mut().peeked() = Some(next);
+                true
+            }
+            Poll::Ready(None) => false,
+            Poll::Pending => {
+                *self.as_mut().peeked() = Some(item);
+                return Poll::Pending;
+            }
+        };
+
+        let started = mem::replace(self.as_mut().started(), true);
+        let position = match (started, has_next) {
+            (false, true) => Position::First,
+            (true, true) => Position::Middle,
+            (true, false) => Position::Last,
+            (false, false) => Position::Only,
+        };
+        Poll::Ready(Some((position, item)))
+    }
+}
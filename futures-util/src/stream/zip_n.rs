@@ -0,0 +1,83 @@
+use crate::stream::{StreamExt, Fuse};
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Waker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+macro_rules! generate {
+    ($(
+        $(#[$doc:meta])*
+        ($Zip:ident, <$(($St:ident, $stream:ident, $queued:ident)),*>),
+    )*) => ($(
+        $(#[$doc])*
+        #[derive(Debug)]
+        #[must_use = "streams do nothing unless polled"]
+        pub struct $Zip<$($St: Stream),*> {
+            $($stream: Fuse<$St>,)*
+            $($queued: Option<$St::Item>,)*
+        }
+
+        impl<$($St: Stream + Unpin),*> Unpin for $Zip<$($St),*> {}
+
+        impl<$($St: Stream),*> $Zip<$($St),*> {
+            $(
+                unsafe_pinned!($stream: Fuse<$St>);
+                unsafe_unpinned!($queued: Option<$St::Item>);
+            )*
+
+            pub(super) fn new($($stream: $St),*) -> $Zip<$($St),*> {
+                $Zip {
+                    $($stream: $stream.fuse(),)*
+                    $($queued: None,)*
+                }
+            }
+        }
+
+        impl<$($St: Stream),*> FusedStream for $Zip<$($St),*> {
+            fn is_terminated(&self) -> bool {
+                // Once one of the streams has ended without leaving an item
+                // behind, no more tuples can be produced.
+                $(self.$stream.is_terminated() && self.$queued.is_none())||*
+            }
+        }
+
+        impl<$($St: Stream),*> Stream for $Zip<$($St),*> {
+            type Item = ($($St::Item),*);
+
+            fn poll_next(
+                mut self: Pin<&mut Self>,
+                waker: &Waker
+            ) -> Poll<Option<Self::Item>> {
+                $(
+                    if self.$queued.is_none() {
+                        match self.as_mut().$stream().poll_next(waker) {
+                            Poll::Ready(Some(item)) => *self.as_mut().$queued() = Some(item),
+                            Poll::Ready(None) | Poll::Pending => {}
+                        }
+                    }
+                )*
+
+                if $(self.$queued.is_some())&&* {
+                    Poll::Ready(Some(($(self.as_mut().$queued().take().unwrap()),*)))
+                } else if $(self.$stream.is_done())||* {
+                    Poll::Ready(None)
+                } else {
+                    Poll::Pending
+                }
+            }
+        }
+    )*)
+}
+
+generate! {
+    /// An adapter for zipping three streams together.
+    ///
+    /// This is created by the `Stream::zip3` method.
+    (Zip3, <(St1, stream1, queued1), (St2, stream2, queued2), (St3, stream3, queued3)>),
+
+    /// An adapter for zipping four streams together.
+    ///
+    /// This is created by the `Stream::zip4` method.
+    (Zip4, <(St1, stream1, queued1), (St2, stream2, queued2), (St3, stream3, queued3),
+            (St4, stream4, queued4)>),
+}
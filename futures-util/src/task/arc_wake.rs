@@ -1,4 +1,4 @@
-use std::mem;
+use std::mem::{self, ManuallyDrop};
 use std::sync::Arc;
 use std::task::{Waker, RawWaker, RawWakerVTable};
 
@@ -19,6 +19,14 @@ pub trait ArcWake {
     /// the associated task onto this queue.
     fn wake(arc_self: &Arc<Self>);
 
+    /// Indicates that the associated task is ready to make progress and should
+    /// be `poll`ed, without touching the reference count of `arc_self`.
+    ///
+    /// The default implementation forwards to [`wake`](ArcWake::wake).
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        Self::wake(arc_self)
+    }
+
     /// Creates a `Waker` from an Arc<T>, if T implements `ArcWake`.
     ///
     /// If `wake()` is called on the returned `Waker`,
@@ -57,9 +65,16 @@ unsafe fn drop_arc_raw<T: ArcWake>(data: *const()) {
 
 // used by `waker_ref`
 pub(super) unsafe fn wake_arc_raw<T: ArcWake>(data: *const()) {
-    let arc: Arc<T> = Arc::from_raw(data as *const T);
+    // Borrow the Arc without ever dropping it
+    let arc = ManuallyDrop::new(Arc::<T>::from_raw(data as *const T));
     ArcWake::wake(&arc);
-    mem::forget(arc);
+}
+
+// To be used for the `wake_by_ref` slot once `RawWakerVTable` gains one.
+#[allow(dead_code)]
+pub(super) unsafe fn wake_by_ref_arc_raw<T: ArcWake>(data: *const()) {
+    let arc = ManuallyDrop::new(Arc::<T>::from_raw(data as *const T));
+    ArcWake::wake_by_ref(&arc);
 }
 
 #[cfg(test)]
@@ -90,6 +105,39 @@ mod tests {
         }
     }
 
+    struct ByRefWaker {
+        nr_wake: Mutex<i32>,
+        nr_wake_by_ref: Mutex<i32>,
+    }
+
+    impl ArcWake for ByRefWaker {
+        fn wake(arc_self: &Arc<Self>) {
+            *arc_self.nr_wake.lock().unwrap() += 1;
+        }
+
+        fn wake_by_ref(arc_self: &Arc<Self>) {
+            *arc_self.nr_wake_by_ref.lock().unwrap() += 1;
+        }
+    }
+
+    #[test]
+    fn waker_calls_wake() {
+        let some_w = Arc::new(ByRefWaker {
+            nr_wake: Mutex::new(0),
+            nr_wake_by_ref: Mutex::new(0),
+        });
+
+        let w: Waker = ArcWake::into_waker(some_w.clone());
+        w.wake();
+        w.wake();
+        assert_eq!(2, *some_w.nr_wake.lock().unwrap());
+        assert_eq!(0, *some_w.nr_wake_by_ref.lock().unwrap());
+        assert_eq!(2, Arc::strong_count(&some_w));
+
+        drop(w);
+        assert_eq!(1, Arc::strong_count(&some_w));
+    }
+
     #[test]
     fn create_waker_from_arc() {
         let some_w = Arc::new(CountingWaker::new());
@@ -0,0 +1,57 @@
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future, TryFuture};
+use futures_core::task::{Waker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`inspect_ok`](super::TryFutureExt::inspect_ok) combinator.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct InspectOk<Fut, F> {
+    future: Fut,
+    f: Option<F>,
+}
+
+impl<Fut, F> InspectOk<Fut, F> {
+    unsafe_pinned!(future: Fut);
+    unsafe_unpinned!(f: Option<F>);
+
+    /// Creates a new InspectOk.
+    pub(super) fn new(future: Fut, f: F) -> InspectOk<Fut, F> {
+        InspectOk { future, f: Some(f) }
+    }
+}
+
+impl<Fut: Unpin, F> Unpin for InspectOk<Fut, F> {}
+
+impl<Fut: FusedFuture, F> FusedFuture for InspectOk<Fut, F> {
+    fn is_terminated(&self) -> bool {
+        // The closure is consumed once the output has been handed out, even
+        // if it panicked.
+        self.f.is_none() || self.future.is_terminated()
+    }
+}
+
+impl<Fut, F> Future for InspectOk<Fut, F>
+    where Fut: TryFuture,
+          F: FnOnce(&Fut::Ok),
+{
+    type Output = Result<Fut::Ok, Fut::Error>;
+
+    fn poll(
+        mut self: Pin<&mut Self>,
+        waker: &Waker,
+    ) -> Poll<Self::Output> {
+        assert!(self.f.is_some(), "cannot poll InspectOk twice");
+
+        match self.as_mut().future().try_poll(waker) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(result) => {
+                let f = self.as_mut().f().take().unwrap();
+                if let Ok(ref x) = result {
+                    f(x);
+                }
+                Poll::Ready(result)
+            }
+        }
+    }
+}
@@ -36,9 +36,18 @@ pub use self::err_into::ErrInto;
 mod flatten_sink;
 pub use self::flatten_sink::FlattenSink;
 
+mod inspect_ok;
+pub use self::inspect_ok::InspectOk;
+
+mod inspect_err;
+pub use self::inspect_err::InspectErr;
+
 mod try_join;
 pub use self::try_join::{TryJoin, TryJoin3, TryJoin4, TryJoin5};
 
+mod try_select;
+pub use self::try_select::{try_select, TrySelect};
+
 mod into_future;
 pub use self::into_future::IntoFuture;
 
@@ -233,6 +242,54 @@ pub trait TryFutureExt: TryFuture {
         ErrInto::new(self)
     }
 
+    /// Do something with the success value of a future before passing it on.
+    ///
+    /// This is similar to [`FutureExt::inspect`](crate::future::FutureExt::inspect),
+    /// but the closure is only called if this future resolves to an [`Ok`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// use futures::future::{self, TryFutureExt};
+    ///
+    /// # futures::executor::block_on(async {
+    /// let future = future::ready(Ok::<i32, i32>(1));
+    /// let future = future.inspect_ok(|&x| println!("about to resolve: {}", x));
+    /// assert_eq!(await!(future), Ok(1));
+    /// # });
+    /// ```
+    fn inspect_ok<F>(self, f: F) -> InspectOk<Self, F>
+        where F: FnOnce(&Self::Ok),
+              Self: Sized,
+    {
+        InspectOk::new(self, f)
+    }
+
+    /// Do something with the error value of a future before passing it on.
+    ///
+    /// This is similar to [`FutureExt::inspect`](crate::future::FutureExt::inspect),
+    /// but the closure is only called if this future resolves to an [`Err`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// use futures::future::{self, TryFutureExt};
+    ///
+    /// # futures::executor::block_on(async {
+    /// let future = future::ready(Err::<i32, i32>(1));
+    /// let future = future.inspect_err(|&e| println!("about to error: {}", e));
+    /// assert_eq!(await!(future), Err(1));
+    /// # });
+    /// ```
+    fn inspect_err<F>(self, f: F) -> InspectErr<Self, F>
+        where F: FnOnce(&Self::Error),
+              Self: Sized,
+    {
+        InspectErr::new(self, f)
+    }
+
     /// Executes another future after this one resolves successfully. The
     /// success value is passed to a closure to create this subsequent future.
     ///
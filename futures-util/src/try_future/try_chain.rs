@@ -27,8 +27,8 @@ impl<Fut1, Fut2, Data> TryChain<Fut1, Fut2, Data>
 
     pub(crate) fn is_terminated(&self) -> bool {
         match self {
-            TryChain::First(..) | TryChain::Second(_) => true,
-            TryChain::Empty => false,
+            TryChain::First(..) | TryChain::Second(_) => false,
+            TryChain::Empty => true,
         }
     }
 
@@ -55,7 +55,9 @@ impl<Fut1, Fut2, Data> TryChain<Fut1, Fut2, Data>
                 }
                 TryChain::Second(fut2) => {
                     // Poll the second future
-                    return unsafe { Pin::new_unchecked(fut2) }.try_poll(waker)
+                    let output = ready!(unsafe { Pin::new_unchecked(fut2) }.try_poll(waker));
+                    *this = TryChain::Empty; // Drop fut2
+                    return Poll::Ready(output);
                 }
                 TryChain::Empty => {
                     panic!("future must not be polled after it returned `Poll::Ready`");
@@ -96,6 +96,10 @@ where
 /// however, then the returned future will succeed with a `Vec` of all the
 /// successful results.
 ///
+/// When an error is returned, all remaining futures and the outputs of the
+/// futures that already completed are dropped before `poll` returns, in the
+/// order in which they were provided.
+///
 /// # Examples
 ///
 /// ```
@@ -168,7 +172,8 @@ where
                 Poll::Ready(Ok(results))
             },
             FinalState::Error(e) => {
-                let _ = mem::replace(&mut self.elems, Box::pin([]));
+                // Dropping the slice drops its elements in index order.
+                drop(mem::replace(&mut self.elems, Box::pin([])));
                 Poll::Ready(Err(e))
             },
         }
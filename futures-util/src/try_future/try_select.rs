@@ -0,0 +1,92 @@
+use core::pin::Pin;
+use either::Either;
+use futures_core::future::{Future, TryFuture};
+use futures_core::task::{Waker, Poll};
+
+/// Future for the [`try_select`] function.
+///
+/// [`try_select`]: fn.try_select.html
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct TrySelect<A, B> {
+    inner: Option<(A, B)>,
+}
+
+impl<A: Unpin, B: Unpin> Unpin for TrySelect<A, B> {}
+
+/// Waits for either one of two differently-typed fallible futures to
+/// complete.
+///
+/// This function will return a new future which awaits for either one of
+/// both futures to complete, no matter whether it succeeded or failed. The
+/// returned future will finish with the result of whichever future completed
+/// first, along with the other future, which is still pending, so that it
+/// can be polled to completion or dropped.
+///
+/// Note that this function consumes the receiving futures and returns a
+/// wrapped version of them. Both futures are polled in order, so `a` wins if
+/// both of them are ready at the same time.
+///
+/// If both futures have the same output type, `Either::factor_first` can be
+/// used to extract the result of the winner while keeping the other future.
+///
+/// # Examples
+///
+/// ```
+/// use futures::executor::block_on;
+/// use futures::future::{self, Either};
+///
+/// let a = future::ready(Err::<u32, _>("failed"));
+/// let b = future::empty::<Result<u32, &str>>();
+///
+/// match block_on(future::try_select(a, b)) {
+///     Either::Left((result, _b)) => assert_eq!(result, Err("failed")),
+///     Either::Right(_) => panic!("b never completes"),
+/// }
+/// ```
+///
+/// Factoring out the result of the winner:
+///
+/// ```
+/// use futures::executor::block_on;
+/// use futures::future::{self, Either};
+///
+/// let a = future::empty::<Result<u32, &str>>();
+/// let b = future::ready(Ok::<u32, &str>(3));
+///
+/// let (result, loser) = block_on(future::try_select(a, b)).factor_first();
+/// assert_eq!(result, Ok(3));
+/// assert!(loser.is_right());
+/// ```
+pub fn try_select<A, B>(a: A, b: B) -> TrySelect<A, B>
+    where A: TryFuture + Unpin,
+          B: TryFuture + Unpin,
+{
+    TrySelect { inner: Some((a, b)) }
+}
+
+impl<A, B> Future for TrySelect<A, B>
+    where A: TryFuture + Unpin,
+          B: TryFuture + Unpin,
+{
+    #[allow(clippy::type_complexity)]
+    type Output = Either<
+        (Result<A::Ok, A::Error>, B),
+        (Result<B::Ok, B::Error>, A),
+    >;
+
+    fn poll(mut self: Pin<&mut Self>, waker: &Waker) -> Poll<Self::Output> {
+        let (mut a, mut b) = self.inner.take().expect("cannot poll TrySelect twice");
+        match Pin::new(&mut a).try_poll(waker) {
+            Poll::Ready(res) => return Poll::Ready(Either::Left((res, b))),
+            Poll::Pending => {}
+        }
+        match Pin::new(&mut b).try_poll(waker) {
+            Poll::Ready(res) => Poll::Ready(Either::Right((res, a))),
+            Poll::Pending => {
+                self.inner = Some((a, b));
+                Poll::Pending
+            }
+        }
+    }
+}
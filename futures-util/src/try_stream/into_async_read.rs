@@ -73,6 +73,8 @@ where
                 }
                 ReadState::PendingChunk => {
                     match ready!(Pin::new(&mut self.stream).try_poll_next(waker)) {
+                        // An empty chunk must not be mistaken for EOF.
+                        Some(Ok(ref chunk)) if chunk.as_ref().is_empty() => continue,
                         Some(Ok(chunk)) => {
                             self.state = ReadState::Ready {
                                 chunk,
@@ -148,4 +150,59 @@ mod tests {
 
         assert_read!(reader, &mut buf, 0);
     }
+
+    #[test]
+    fn test_into_async_read_partial_chunk() {
+        use std::cell::Cell;
+
+        let pulled = Cell::new(0);
+        let stream = stream::iter(vec![Ok((1..=10).collect::<Vec<u8>>())])
+            .inspect(|_| pulled.set(pulled.get() + 1));
+        let mut reader = stream.into_async_read();
+        let mut buf = vec![0; 4];
+
+        assert_read!(reader, &mut buf, 4);
+        assert_eq!(&buf, &[1, 2, 3, 4]);
+        assert_read!(reader, &mut buf, 4);
+        assert_eq!(&buf, &[5, 6, 7, 8]);
+        // The remainder of the chunk is served without pulling the stream.
+        assert_eq!(pulled.get(), 1);
+        assert_read!(reader, &mut buf, 2);
+        assert_eq!(&buf[..2], &[9, 10]);
+        assert_eq!(pulled.get(), 1);
+
+        assert_read!(reader, &mut buf, 0);
+    }
+
+    #[test]
+    fn test_into_async_read_skips_empty_chunks() {
+        let stream = stream::iter(vec![Ok(vec![]), Ok(vec![1, 2]), Ok(vec![])]);
+        let mut reader = stream.into_async_read();
+        let mut buf = vec![0; 4];
+
+        assert_read!(reader, &mut buf, 2);
+        assert_eq!(&buf[..2], &[1, 2]);
+        assert_read!(reader, &mut buf, 0);
+    }
+
+    #[test]
+    fn test_into_async_read_error() {
+        use std::io::ErrorKind;
+
+        let stream = stream::iter(vec![
+            Ok(vec![1, 2]),
+            Err(Error::new(ErrorKind::Other, "boom")),
+            Ok(vec![3]),
+        ]);
+        let mut reader = stream.into_async_read();
+        let mut buf = vec![0; 4];
+        let waker = noop_waker_ref();
+
+        assert_read!(reader, &mut buf, 2);
+        match reader.poll_read(waker, &mut buf) {
+            Poll::Ready(Err(err)) => assert_eq!(err.kind(), ErrorKind::Other),
+            _ => panic!("expected the stream error"),
+        }
+        assert_read!(reader, &mut buf, 0);
+    }
 }
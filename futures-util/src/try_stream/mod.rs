@@ -32,6 +32,9 @@ pub use self::try_for_each::TryForEach;
 mod try_filter_map;
 pub use self::try_filter_map::TryFilterMap;
 
+mod try_filter_map_ok;
+pub use self::try_filter_map_ok::TryFilterMapOk;
+
 mod try_concat;
 pub use self::try_concat::TryConcat;
 
@@ -212,6 +215,10 @@ pub trait TryStreamExt: TryStream {
     /// futures or the stream returns an error, this future will return
     /// immediately with an error.
     ///
+    /// An error yielded by the stream is returned without calling the closure,
+    /// and an error from one of the closure's futures is returned without
+    /// pulling another item from the stream.
+    ///
     /// # Examples
     ///
     /// ```
@@ -279,6 +286,16 @@ pub trait TryStreamExt: TryStream {
     /// but will resolve to an error immediately if the underlying stream or the provided
     /// closure return an error.
     ///
+    /// The first argument is an optional limit on the number of concurrent
+    /// futures. If this limit is not `None`, no more than `limit` futures
+    /// will be run concurrently. The `limit` argument is of type
+    /// `Into<Option<usize>>`, and so can be provided as either `None`,
+    /// `Some(10)`, or just `10`. Note: a limit of zero is interpreted as
+    /// no limit at all, and will have the same result as passing in `None`.
+    ///
+    /// Once an error is returned, the futures which are still running are
+    /// dropped and no more items are pulled from the stream.
+    ///
     /// This method is only available when the `std` feature of this
     /// library is activated, and it is activated by default.
     ///
@@ -408,6 +425,48 @@ pub trait TryStreamExt: TryStream {
         TryFilterMap::new(self, f)
     }
 
+    /// Attempt to filter the values produced by this stream while
+    /// simultaneously mapping them to a different type according to the
+    /// provided synchronous closure.
+    ///
+    /// This is the synchronous counterpart of
+    /// [`try_filter_map`](TryStreamExt::try_filter_map): the closure is run
+    /// directly on each successful value, so no future has to be created or
+    /// stored for it. If the closure returns [`Ok(Some(item))`](Some) then the
+    /// stream will yield `item`, if it returns `Ok(None)` then the next value
+    /// will be produced, and if it returns an error then that error is
+    /// yielded.
+    ///
+    /// All errors of the underlying stream are passed through without
+    /// filtering in this combinator.
+    ///
+    /// # Examples
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt, TryStreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok(1i32), Ok(6), Ok(-4), Err("error")]);
+    /// let halves = stream.try_filter_map_ok(|x| {
+    ///     if x < 0 {
+    ///         Err("negative")
+    ///     } else if x % 2 == 0 {
+    ///         Ok(Some(x / 2))
+    ///     } else {
+    ///         Ok(None)
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(
+    ///     block_on(halves.collect::<Vec<_>>()),
+    ///     vec![Ok(3), Err("negative"), Err("error")],
+    /// );
+    /// ```
+    fn try_filter_map_ok<T, F>(self, f: F) -> TryFilterMapOk<Self, F>
+        where F: FnMut(Self::Ok) -> Result<Option<T>, Self::Error>,
+              Self: Sized
+    {
+        TryFilterMapOk::new(self, f)
+    }
 
     /// Attempt to execute an accumulating asynchronous computation over a
     /// stream, collecting all the values into one final result.
@@ -494,8 +553,11 @@ pub trait TryStreamExt: TryStream {
     /// that matches the stream's `Error` type.
     ///
     /// This adaptor will buffer up to `n` futures and then return their
-    /// outputs in the order in which they complete. If the underlying stream
-    /// returns an error, it will be immediately propagated.
+    /// outputs in the order in which they complete. If `n` is `0`, the number
+    /// of buffered futures is unbounded, the same as for
+    /// [`StreamExt::buffer_unordered`](crate::stream::StreamExt::buffer_unordered).
+    /// If the underlying stream returns an error, it will be immediately
+    /// propagated.
     ///
     /// The returned stream will be a stream of results, each containing either
     /// an error or a future's output. An error can be produced either by the
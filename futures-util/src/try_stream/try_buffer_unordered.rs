@@ -1,10 +1,10 @@
-use crate::stream::{Fuse, FuturesUnordered, StreamExt};
-use crate::try_future::{IntoFuture, TryFutureExt};
+use crate::stream::{Buffering, Fuse, FuturesUnordered, StreamExt, poll_buffered};
+use crate::try_future::IntoFuture;
 use crate::try_stream::IntoStream;
+use core::num::NonZeroUsize;
 use futures_core::future::TryFuture;
 use futures_core::stream::{Stream, TryStream};
 use futures_core::task::{Waker, Poll};
-use pin_utils::{unsafe_pinned, unsafe_unpinned};
 use std::pin::Pin;
 
 /// A stream returned by the
@@ -16,7 +16,7 @@ pub struct TryBufferUnordered<St>
 {
     stream: Fuse<IntoStream<St>>,
     in_progress_queue: FuturesUnordered<IntoFuture<St::Ok>>,
-    max: usize,
+    max: Option<NonZeroUsize>,
 }
 
 impl<St> Unpin for TryBufferUnordered<St>
@@ -27,14 +27,12 @@ impl<St> TryBufferUnordered<St>
     where St: TryStream,
           St::Ok: TryFuture,
 {
-    unsafe_pinned!(stream: Fuse<IntoStream<St>>);
-    unsafe_unpinned!(in_progress_queue: FuturesUnordered<IntoFuture<St::Ok>>);
-
     pub(super) fn new(stream: St, n: usize) -> Self {
         TryBufferUnordered {
             stream: IntoStream::new(stream).fuse(),
             in_progress_queue: FuturesUnordered::new(),
-            max: n,
+            // Note: `n` = 0 gets ignored.
+            max: NonZeroUsize::new(n),
         }
     }
 
@@ -69,30 +67,22 @@ impl<St> Stream for TryBufferUnordered<St>
     type Item = Result<<St::Ok as TryFuture>::Ok, St::Error>;
 
     fn poll_next(
-        mut self: Pin<&mut Self>,
+        self: Pin<&mut Self>,
         waker: &Waker,
     ) -> Poll<Option<Self::Item>> {
-        // First up, try to spawn off as many futures as possible by filling up
-        // our slab of futures. Propagate errors from the stream immediately.
-        while self.in_progress_queue.len() < self.max {
-            match self.as_mut().stream().poll_next(waker) {
-                Poll::Ready(Some(Ok(fut))) => self.as_mut().in_progress_queue().push(fut.into_future()),
-                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
-                Poll::Ready(None) | Poll::Pending => break,
-            }
-        }
+        poll_buffered(self, waker)
+    }
+}
 
-        // Attempt to pull the next value from the in_progress_queue
-        match Pin::new(self.as_mut().in_progress_queue()).poll_next(waker) {
-            x @ Poll::Pending | x @ Poll::Ready(Some(_)) => return x,
-            Poll::Ready(None) => {}
-        }
+impl<St> Buffering for TryBufferUnordered<St>
+    where St: TryStream,
+          St::Ok: TryFuture<Error = St::Error>,
+{
+    type Stream = IntoStream<St>;
+    type Future = IntoFuture<St::Ok>;
+    type Queue = FuturesUnordered<IntoFuture<St::Ok>>;
 
-        // If more values are still coming from the stream, we're not done yet
-        if self.stream.is_done() {
-            Poll::Ready(None)
-        } else {
-            Poll::Pending
-        }
+    fn fields(&mut self) -> (&mut Fuse<Self::Stream>, &mut Self::Queue, Option<NonZeroUsize>) {
+        (&mut self.stream, &mut self.in_progress_queue, self.max)
     }
 }
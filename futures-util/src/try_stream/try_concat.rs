@@ -49,10 +49,16 @@ where
                     if let Some(a) = accum {
                         a.extend(x)
                     } else {
+                        // The first item becomes the accumulator as is, so a
+                        // single-item stream resolves to that exact item.
                         *accum = Some(x)
                     }
                 },
-                Some(Err(e)) => return Poll::Ready(Err(e)),
+                Some(Err(e)) => {
+                    // Release whatever was accumulated so far right away.
+                    *self.as_mut().accum() = None;
+                    return Poll::Ready(Err(e))
+                }
                 None => {
                     return Poll::Ready(Ok(self.as_mut().accum().take().unwrap_or_default()))
                 }
@@ -0,0 +1,79 @@
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream, TryStream};
+use futures_core::task::{Waker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`try_filter_map_ok`](super::TryStreamExt::try_filter_map_ok)
+/// combinator.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct TryFilterMapOk<St, F> {
+    stream: St,
+    f: F,
+}
+
+impl<St, F> TryFilterMapOk<St, F> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+
+    /// Creates a new TryFilterMapOk.
+    pub(super) fn new(stream: St, f: F) -> Self {
+        TryFilterMapOk { stream, f }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St: Unpin, F> Unpin for TryFilterMapOk<St, F> {}
+
+impl<St: FusedStream, F> FusedStream for TryFilterMapOk<St, F> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, F, T> Stream for TryFilterMapOk<St, F>
+where
+    St: TryStream,
+    F: FnMut(St::Ok) -> Result<Option<T>, St::Error>,
+{
+    type Item = Result<T, St::Error>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        waker: &Waker,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            match ready!(self.as_mut().stream().try_poll_next(waker)) {
+                Some(Ok(item)) => match (self.as_mut().f())(item) {
+                    Ok(Some(item)) => return Poll::Ready(Some(Ok(item))),
+                    Ok(None) => {}
+                    Err(e) => return Poll::Ready(Some(Err(e))),
+                },
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
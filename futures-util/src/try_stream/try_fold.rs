@@ -50,17 +50,22 @@ impl<St, Fut, T, F> Future for TryFold<St, Fut, T, F>
     type Output = Result<T, St::Error>;
 
     fn poll(mut self: Pin<&mut Self>, waker: &Waker) -> Poll<Self::Output> {
+        // Both `accum` and `future` are cleared once a result, successful or
+        // not, has been returned.
+        if self.is_terminated() {
+            panic!("TryFold polled after completion");
+        }
+
         loop {
             // we're currently processing a future to produce a new accum value
             if self.as_mut().accum().is_none() {
                 let accum = match ready!(
-                    self.as_mut().future().as_pin_mut()
-                       .expect("TryFold polled after completion")
-                       .try_poll(waker)
+                    self.as_mut().future().as_pin_mut().unwrap().try_poll(waker)
                 ) {
                     Ok(accum) => accum,
                     Err(e) => {
                         // Indicate that the future can no longer be polled.
+                        // `accum` is already `None` at this point.
                         self.as_mut().future().set(None);
                         return Poll::Ready(Err(e));
                     }
@@ -73,6 +78,7 @@ impl<St, Fut, T, F> Future for TryFold<St, Fut, T, F>
                 Some(Ok(item)) => Some(item),
                 Some(Err(e)) => {
                     // Indicate that the future can no longer be polled.
+                    // `future` is already `None` at this point.
                     *self.as_mut().accum() = None;
                     return Poll::Ready(Err(e));
                 }
@@ -1,5 +1,5 @@
 use core::pin::Pin;
-use futures_core::future::{Future, TryFuture};
+use futures_core::future::{FusedFuture, Future, TryFuture};
 use futures_core::stream::TryStream;
 use futures_core::task::{Waker, Poll};
 use pin_utils::{unsafe_pinned, unsafe_unpinned};
@@ -14,6 +14,7 @@ pub struct TryForEach<St, Fut, F> {
     stream: St,
     f: F,
     future: Option<Fut>,
+    done: bool,
 }
 
 impl<St: Unpin, Fut: Unpin, F> Unpin for TryForEach<St, Fut, F> {}
@@ -26,16 +27,24 @@ where St: TryStream,
     unsafe_pinned!(stream: St);
     unsafe_unpinned!(f: F);
     unsafe_pinned!(future: Option<Fut>);
+    unsafe_unpinned!(done: bool);
 
     pub(super) fn new(stream: St, f: F) -> TryForEach<St, Fut, F> {
         TryForEach {
             stream,
             f,
             future: None,
+            done: false,
         }
     }
 }
 
+impl<St, Fut, F> FusedFuture for TryForEach<St, Fut, F> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
 impl<St, Fut, F> Future for TryForEach<St, Fut, F>
     where St: TryStream,
           F: FnMut(St::Ok) -> Fut,
@@ -46,17 +55,29 @@ impl<St, Fut, F> Future for TryForEach<St, Fut, F>
     fn poll(mut self: Pin<&mut Self>, waker: &Waker) -> Poll<Self::Output> {
         loop {
             if let Some(future) = self.as_mut().future().as_pin_mut() {
-                try_ready!(future.try_poll(waker));
+                if let Err(e) = ready!(future.try_poll(waker)) {
+                    // Don't pull another item from the stream once the
+                    // closure's future has failed.
+                    self.as_mut().future().set(None);
+                    *self.as_mut().done() = true;
+                    return Poll::Ready(Err(e));
+                }
             }
-            Pin::set(&mut self.as_mut().future(), None);
+            self.as_mut().future().set(None);
 
             match ready!(self.as_mut().stream().try_poll_next(waker)) {
                 Some(Ok(e)) => {
                     let future = (self.as_mut().f())(e);
-                    Pin::set(&mut self.as_mut().future(), Some(future));
+                    self.as_mut().future().set(Some(future));
+                }
+                Some(Err(e)) => {
+                    *self.as_mut().done() = true;
+                    return Poll::Ready(Err(e));
+                }
+                None => {
+                    *self.as_mut().done() = true;
+                    return Poll::Ready(Ok(()));
                 }
-                Some(Err(e)) => return Poll::Ready(Err(e)),
-                None => return Poll::Ready(Ok(())),
             }
         }
     }
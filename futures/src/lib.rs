@@ -54,6 +54,8 @@ compile_error!("The `cfg-target-has-atomic` feature requires the `nightly` featu
 pub use futures_util::{
     // Error/readiness propagation
     try_ready, ready,
+    // Stream merging
+    merge,
 };
 #[cfg(feature = "std")]
 pub use futures_util::{
@@ -156,7 +158,8 @@ pub mod executor {
         Enter, EnterError,
         LocalSpawner, LocalPool,
         ThreadPool, ThreadPoolBuilder,
-        block_on, block_on_stream, enter,
+        block_on, block_on_with, block_on_stream, enter,
+        Park, UnparkToken, StdParker,
     };
 }
 
@@ -183,13 +186,15 @@ pub mod future {
         lazy, Lazy,
         maybe_done, MaybeDone,
         poll_fn, PollFn,
+        race, Race,
         ready, ok, err, Ready,
 
         OptionFuture,
+        Either,
 
         FutureExt,
         FlattenStream, Flatten, Fuse, Inspect, IntoStream, Join, Join3, Join4,
-        Join5, Map, Then,
+        Join5, Map, Then, WithContext,
     };
 
     #[cfg(feature = "std")]
@@ -197,7 +202,7 @@ pub mod future {
         abortable, Abortable, AbortHandle, AbortRegistration, Aborted,
         Remote, RemoteHandle,
         // For FutureExt:
-        CatchUnwind, Shared,
+        CatchUnwind, Shared, LocalBoxFuture,
 
         join_all, JoinAll,
 
@@ -206,9 +211,10 @@ pub mod future {
 
     pub use futures_util::try_future::{
         TryFutureExt,
-        AndThen, ErrInto, FlattenSink, IntoFuture, MapErr, MapOk, OrElse,
-        UnwrapOrElse,
+        AndThen, ErrInto, FlattenSink, InspectOk, InspectErr, IntoFuture,
+        MapErr, MapOk, OrElse, UnwrapOrElse,
         TryJoin, TryJoin3, TryJoin4, TryJoin5,
+        try_select, TrySelect,
     };
 
     #[cfg(feature = "std")]
@@ -236,11 +242,17 @@ pub mod io {
     //! sinks.
 
     pub use futures_io::{
-        Error, Initializer, IoVec, ErrorKind, AsyncRead, AsyncWrite, Result
+        Error, Initializer, IoVec, ErrorKind, AsyncRead, AsyncBufRead,
+        AsyncWrite, Result,
     };
     pub use futures_util::io::{
-        AsyncReadExt, AsyncWriteExt, AllowStdIo, Close, CopyInto, Flush,
-        Read, ReadExact, ReadHalf, ReadToEnd, Window, WriteAll, WriteHalf,
+        empty, Empty,
+        repeat, Repeat,
+        sink, Sink,
+
+        AsyncReadExt, AsyncWriteExt, AllowStdIo, Close, copy_buf, CopyBuf,
+        CopyInto, Flush, Read, ReadExact, ReadHalf, ReadToEnd, Window,
+        WriteAll, WriteAllAndFlush, WriteHalf,
     };
 }
 
@@ -315,15 +327,19 @@ pub mod stream {
     pub use futures_util::stream::{
         iter, Iter,
         repeat, Repeat,
+        replicate, Replicate,
         empty, Empty,
         once, Once,
         poll_fn, PollFn,
+        from_fn, FromFn,
         unfold, Unfold,
 
         StreamExt,
-        Chain, Concat, Filter, FilterMap, Flatten, Fold, Forward, ForEach, Fuse,
-        StreamFuture, Inspect, Map, Next, Peekable, Select, Skip, SkipWhile,
-        Take, TakeWhile, Then, Zip
+        Chain, CollectInto, Concat, CountIf, Drain, Filter, FilterMap, Flatten,
+        Fold, FoldWhile, ControlFlow, Forward, ForEach, Fuse, StreamFuture,
+        Inspect, InspectDone, Map, MapComposed, Merge3, Merge4, Merge5, Next,
+        Peekable, Select, Skip, SkipWhile, Take, TakeWhile, Then, WithContext,
+        WithPosition, Position, Zip, Zip3, Zip4, ZipLongest, EitherOrBoth,
     };
 
     #[cfg(feature = "std")]
@@ -332,8 +348,9 @@ pub mod stream {
         futures_unordered, FuturesUnordered,
 
         // For StreamExt:
-        BufferUnordered, Buffered, CatchUnwind, Chunks, Collect, SplitStream,
-        SplitSink, ReuniteError,
+        BufferUnordered, Buffered, CatchUnwind, Chunks, ChunksExact, Collect,
+        FlatMapUnordered, SplitStream, SplitSink, ReuniteError, OkStream,
+        ErrStream, LocalBoxStream, Metered, ReadyChunks,
 
         select_all, SelectAll,
     };
@@ -341,7 +358,7 @@ pub mod stream {
     pub use futures_util::try_stream::{
         TryStreamExt,
         TryNext, TryForEach, ErrInto,
-        TryFold, TrySkipWhile,
+        TryFold, TrySkipWhile, TryFilterMapOk,
         IntoStream,
         // ToDo: AndThen, ErrInto, InspectErr, MapErr, OrElse
     };
@@ -386,6 +403,24 @@ pub mod task {
     pub use futures_util::task::AtomicWaker;
 }
 
+// `stream!` re-export --------------------------------------
+
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub mod inner_stream {
+    pub use futures_util::stream;
+}
+
+#[cfg(feature = "std")]
+futures_util::document_stream_macro! {
+    #[macro_export]
+    macro_rules! stream { // forwarded, as `stream` also names the `stream` module
+        ($($tokens:tt)*) => {
+            $crate::inner_stream::stream! { $( $tokens )* }
+        }
+    }
+}
+
 // `select!` re-export --------------------------------------
 
 #[cfg(feature = "std")]
@@ -38,3 +38,17 @@ fn abortable_resolves() {
 
     assert_eq!(Ok(Ok(())), block_on(abortable_rx));
 }
+
+#[test]
+fn abort_reports_first_call_only() {
+    let (_tx, a_rx) = oneshot::channel::<()>();
+    let (mut abortable_rx, abort_handle) = abortable(a_rx);
+    let (waker, counter) = new_count_waker();
+
+    assert_eq!(Poll::Pending, abortable_rx.poll_unpin(&waker));
+    assert!(abort_handle.clone().abort());
+    assert!(!abort_handle.abort());
+    // The registered waker is consumed by the first wake-up.
+    assert_eq!(counter, 1);
+    assert_eq!(Poll::Ready(Err(Aborted)), abortable_rx.poll_unpin(&waker));
+}
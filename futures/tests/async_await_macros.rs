@@ -213,3 +213,31 @@ fn try_join_doesnt_require_unpin() {
         try_join!(x, y)
     };
 }
+
+#[test]
+fn select_on_terminated_fuse() {
+    use futures::future::{Fuse, FusedFuture};
+
+    let mut fired = Vec::new();
+    let mut completed = 0;
+    block_on(async {
+        let fut = Fuse::terminated();
+        pin_mut!(fut);
+
+        // A terminated branch never fires.
+        select! {
+            x = fut => fired.push(x),
+            complete => completed += 1,
+        }
+        assert!(fired.is_empty());
+
+        fut.set(future::ready(1).fuse());
+        assert!(!fut.is_terminated());
+        select! {
+            x = fut => fired.push(x),
+            complete => completed += 1,
+        }
+    });
+    assert_eq!(fired, vec![1]);
+    assert_eq!(completed, 1);
+}
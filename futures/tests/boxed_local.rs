@@ -0,0 +1,41 @@
+#![feature(futures_api)]
+
+use futures::executor::block_on;
+use futures::future::{self, FutureExt, LocalBoxFuture};
+use futures::stream::{self, LocalBoxStream, StreamExt};
+use std::cell::Cell;
+use std::rc::Rc;
+
+fn counter(count: Rc<Cell<u32>>) -> LocalBoxStream<'static, u32> {
+    // `Rc` makes this stream `!Send`.
+    stream::iter(1..=3)
+        .map(move |x| {
+            count.set(count.get() + 1);
+            x
+        })
+        .boxed_local()
+}
+
+#[test]
+fn boxed_local_stream() {
+    let count = Rc::new(Cell::new(0));
+    let vec = block_on(counter(count.clone()).collect::<Vec<_>>());
+    assert_eq!(vec, vec![1, 2, 3]);
+    assert_eq!(count.get(), 3);
+}
+
+#[test]
+fn boxed_local_stream_borrowing() {
+    let items = vec![1, 2, 3];
+    let stream: LocalBoxStream<'_, &i32> = stream::iter(items.iter()).boxed_local();
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![&1, &2, &3]);
+}
+
+#[test]
+fn boxed_local_future() {
+    fn answer(value: Rc<u32>) -> LocalBoxFuture<'static, u32> {
+        future::ready(()).map(move |()| *value).boxed_local()
+    }
+
+    assert_eq!(block_on(answer(Rc::new(42))), 42);
+}
@@ -0,0 +1,106 @@
+#![feature(futures_api)]
+
+use futures::channel::{mpsc, oneshot};
+use futures::sink::Sink;
+use futures::stream::{FusedStream, Stream, StreamExt};
+use futures::task::{Waker, Poll};
+use futures_test::task::noop_waker_ref;
+use pin_utils::unsafe_pinned;
+use std::pin::Pin;
+
+struct Join<T, U> {
+    stream: T,
+    sink: U,
+}
+
+impl<T, U> Join<T, U> {
+    unsafe_pinned!(stream: T);
+    unsafe_pinned!(sink: U);
+}
+
+impl<T: Stream, U> Stream for Join<T, U> {
+    type Item = T::Item;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        waker: &Waker,
+    ) -> Poll<Option<T::Item>> {
+        self.stream().poll_next(waker)
+    }
+}
+
+impl<T, U: Sink> Sink for Join<T, U> {
+    type SinkItem = U::SinkItem;
+    type SinkError = U::SinkError;
+
+    fn poll_ready(
+        self: Pin<&mut Self>,
+        waker: &Waker,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.sink().poll_ready(waker)
+    }
+
+    fn start_send(
+        self: Pin<&mut Self>,
+        item: Self::SinkItem,
+    ) -> Result<(), Self::SinkError> {
+        self.sink().start_send(item)
+    }
+
+    fn poll_flush(
+        self: Pin<&mut Self>,
+        waker: &Waker,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.sink().poll_flush(waker)
+    }
+
+    fn poll_close(
+        self: Pin<&mut Self>,
+        waker: &Waker,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.sink().poll_close(waker)
+    }
+}
+
+#[test]
+fn forwards_sink_while_buffering() {
+    let (tx, rx) = mpsc::unbounded();
+    let (otx1, orx1) = oneshot::channel::<i32>();
+    let (otx2, orx2) = oneshot::channel::<i32>();
+    let lw = noop_waker_ref();
+
+    let mut s = Join { stream: rx, sink: Vec::new() }.buffer_unordered(10);
+
+    assert!(Pin::new(&mut s).poll_ready(lw).is_ready());
+    Pin::new(&mut s).start_send(1).unwrap();
+
+    tx.unbounded_send(orx1).unwrap();
+    tx.unbounded_send(orx2).unwrap();
+    assert_eq!(s.poll_next_unpin(lw), Poll::Pending);
+
+    // Both futures are now in flight; the sink half must be unaffected.
+    assert!(Pin::new(&mut s).poll_ready(lw).is_ready());
+    Pin::new(&mut s).start_send(2).unwrap();
+    assert!(Pin::new(&mut s).poll_flush(lw).is_ready());
+    assert_eq!(s.get_ref().sink, vec![1, 2]);
+
+    // The source ending doesn't terminate the stream while futures are
+    // still in flight.
+    drop(tx);
+    assert_eq!(s.poll_next_unpin(lw), Poll::Pending);
+    assert!(!s.is_terminated());
+
+    otx2.send(20).unwrap();
+    assert_eq!(s.poll_next_unpin(lw), Poll::Ready(Some(Ok(20))));
+    assert!(!s.is_terminated());
+
+    otx1.send(10).unwrap();
+    assert_eq!(s.poll_next_unpin(lw), Poll::Ready(Some(Ok(10))));
+    assert!(!s.is_terminated());
+
+    assert_eq!(s.poll_next_unpin(lw), Poll::Ready(None));
+    assert!(s.is_terminated());
+
+    assert!(Pin::new(&mut s).poll_close(lw).is_ready());
+    assert_eq!(s.into_inner().sink, vec![1, 2]);
+}
@@ -0,0 +1,102 @@
+#![feature(futures_api)]
+
+use futures::channel::{mpsc, oneshot};
+use futures::future;
+use futures::stream::{self, FusedStream, StreamExt, TryStreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_waker_ref;
+use std::cell::Cell;
+
+#[test]
+fn buffered_yields_in_order() {
+    let (send_one, recv_one) = oneshot::channel::<i32>();
+    let (send_two, recv_two) = oneshot::channel::<i32>();
+    let lw = noop_waker_ref();
+
+    let mut buffered = stream::iter(vec![recv_one, recv_two]).buffered(10);
+    assert_eq!(buffered.poll_next_unpin(lw), Poll::Pending);
+
+    send_two.send(2).unwrap();
+    assert_eq!(buffered.poll_next_unpin(lw), Poll::Pending);
+
+    send_one.send(1).unwrap();
+    assert_eq!(buffered.poll_next_unpin(lw), Poll::Ready(Some(Ok(1))));
+    assert_eq!(buffered.poll_next_unpin(lw), Poll::Ready(Some(Ok(2))));
+    assert_eq!(buffered.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn buffer_unordered_yields_in_completion_order() {
+    let (send_one, recv_one) = oneshot::channel::<i32>();
+    let (send_two, recv_two) = oneshot::channel::<i32>();
+    let lw = noop_waker_ref();
+
+    let mut buffered = stream::iter(vec![recv_one, recv_two]).buffer_unordered(10);
+    assert_eq!(buffered.poll_next_unpin(lw), Poll::Pending);
+
+    send_two.send(2).unwrap();
+    assert_eq!(buffered.poll_next_unpin(lw), Poll::Ready(Some(Ok(2))));
+
+    send_one.send(1).unwrap();
+    assert_eq!(buffered.poll_next_unpin(lw), Poll::Ready(Some(Ok(1))));
+    assert!(!buffered.is_terminated());
+    assert_eq!(buffered.poll_next_unpin(lw), Poll::Ready(None));
+    assert!(buffered.is_terminated());
+}
+
+#[test]
+fn try_buffer_unordered_yields_in_completion_order() {
+    let (send_one, recv_one) = oneshot::channel::<i32>();
+    let (send_two, recv_two) = oneshot::channel::<i32>();
+    let lw = noop_waker_ref();
+
+    let mut buffered = stream::iter(vec![Ok(recv_one), Ok(recv_two)])
+        .try_buffer_unordered(10);
+    assert_eq!(buffered.poll_next_unpin(lw), Poll::Pending);
+
+    send_two.send(2).unwrap();
+    assert_eq!(buffered.poll_next_unpin(lw), Poll::Ready(Some(Ok(2))));
+
+    send_one.send(1).unwrap();
+    assert_eq!(buffered.poll_next_unpin(lw), Poll::Ready(Some(Ok(1))));
+    assert_eq!(buffered.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn try_buffer_unordered_propagates_stream_errors() {
+    let (sink, stream_of_futures) = mpsc::unbounded();
+    let lw = noop_waker_ref();
+
+    let mut buffered = stream_of_futures.try_buffer_unordered(10);
+
+    sink.unbounded_send(Ok(future::ready(Ok(7i32)))).unwrap();
+    assert_eq!(buffered.poll_next_unpin(lw), Poll::Ready(Some(Ok(7))));
+
+    sink.unbounded_send(Err("error in the stream")).unwrap();
+    assert_eq!(buffered.poll_next_unpin(lw), Poll::Ready(Some(Err("error in the stream"))));
+
+    drop(sink);
+    assert_eq!(buffered.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn buffering_respects_limit() {
+    let lw = noop_waker_ref();
+    let (txs, rxs): (Vec<_>, Vec<_>) = (0..3).map(|_| oneshot::channel::<i32>()).unzip();
+    let mut txs = txs.into_iter();
+    let pulled = Cell::new(0);
+
+    let mut buffered = stream::iter(rxs)
+        .inspect(|_| pulled.set(pulled.get() + 1))
+        .buffered(2);
+    assert_eq!(buffered.poll_next_unpin(lw), Poll::Pending);
+    assert_eq!(pulled.get(), 2);
+
+    txs.next().unwrap().send(1).unwrap();
+    assert_eq!(buffered.poll_next_unpin(lw), Poll::Ready(Some(Ok(1))));
+    assert_eq!(pulled.get(), 2);
+
+    // Only once the first future has completed is the third one pulled.
+    assert_eq!(buffered.poll_next_unpin(lw), Poll::Pending);
+    assert_eq!(pulled.get(), 3);
+}
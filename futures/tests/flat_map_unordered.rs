@@ -0,0 +1,69 @@
+#![feature(async_await, futures_api)]
+
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::stream::{self, FusedStream, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_waker_ref;
+
+#[test]
+fn interleaves_sub_streams() {
+    let (tx1, rx1) = mpsc::unbounded::<i32>();
+    let (tx2, rx2) = mpsc::unbounded::<i32>();
+    let lw = noop_waker_ref();
+
+    let mut stream = stream::iter(vec![rx1, rx2])
+        .flat_map_unordered(None, |rx| rx.map(|x| x * 10));
+
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Pending);
+
+    tx2.unbounded_send(1).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(10)));
+    tx1.unbounded_send(2).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(20)));
+    tx2.unbounded_send(3).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(30)));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Pending);
+
+    drop(tx1);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Pending);
+    assert!(!stream.is_terminated());
+
+    drop(tx2);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+    assert!(stream.is_terminated());
+}
+
+#[test]
+fn respects_limit() {
+    let (tx1, rx1) = mpsc::unbounded::<i32>();
+    let (tx2, rx2) = mpsc::unbounded::<i32>();
+    let lw = noop_waker_ref();
+
+    let mut stream = stream::iter(vec![rx1, rx2]).flat_map_unordered(1, |rx| rx);
+
+    // The second sub-stream isn't started until the first one finishes.
+    tx2.unbounded_send(2).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Pending);
+    tx1.unbounded_send(1).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Pending);
+
+    drop(tx1);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(2)));
+
+    drop(tx2);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+    assert!(stream.is_terminated());
+}
+
+#[test]
+fn boxed_non_unpin_sub_streams() {
+    // `async` blocks aren't `Unpin`, so the sub-streams are pinned in a box.
+    let stream = stream::iter(1..=3)
+        .flat_map_unordered(2, |x| Box::pin(stream::once(async move { x * 10 })));
+
+    let mut values = block_on(stream.collect::<Vec<_>>());
+    values.sort();
+    assert_eq!(values, vec![10, 20, 30]);
+}
@@ -0,0 +1,45 @@
+#![feature(futures_api)]
+
+use futures::channel::mpsc;
+use futures::future::{self, FutureExt};
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::task::new_count_waker;
+use std::cell::Cell;
+
+#[test]
+fn pending_source_without_in_flight_futures_does_not_spin() {
+    let (tx, mut rx) = mpsc::unbounded::<i32>();
+    let (waker, count) = new_count_waker();
+    let source_polls = Cell::new(0);
+    let processed = Cell::new(0);
+
+    let source = stream::poll_fn(|waker| {
+        source_polls.set(source_polls.get() + 1);
+        rx.poll_next_unpin(waker)
+    });
+    let mut fut = source.for_each_concurrent(None, |_| {
+        processed.set(processed.get() + 1);
+        future::ready(())
+    });
+
+    // Nothing is in flight and the source is pending: the source must be
+    // polled exactly once and nobody may have been woken.
+    assert_eq!(fut.poll_unpin(&waker), Poll::Pending);
+    assert_eq!(source_polls.get(), 1);
+    assert_eq!(count, 0);
+
+    // A new item wakes the task through the source.
+    tx.unbounded_send(1).unwrap();
+    assert_eq!(count, 1);
+
+    assert_eq!(fut.poll_unpin(&waker), Poll::Pending);
+    assert_eq!(processed.get(), 1);
+    // One poll to get the item, one more to find the source pending again.
+    assert_eq!(source_polls.get(), 3);
+    assert_eq!(count, 1);
+
+    drop(tx);
+    assert_eq!(count, 2);
+    assert_eq!(fut.poll_unpin(&waker), Poll::Ready(()));
+}
@@ -10,3 +10,18 @@ fn fuse() {
     assert!(future.poll_unpin(lw).is_ready());
     assert!(future.poll_unpin(lw).is_pending());
 }
+
+#[test]
+fn terminated() {
+    use futures::future::{Fuse, FusedFuture};
+
+    let mut future = Fuse::terminated();
+    let lw = &mut panic_waker();
+    assert!(future.is_terminated());
+    assert!(future.poll_unpin(lw).is_pending());
+
+    future = future::ready(1).fuse();
+    assert!(!future.is_terminated());
+    assert!(future.poll_unpin(lw).is_ready());
+    assert!(future.is_terminated());
+}
@@ -4,6 +4,7 @@ use futures::channel::oneshot;
 use futures::executor::{block_on, block_on_stream};
 use futures::future::{self, FutureExt, FutureObj};
 use futures::stream::{StreamExt, futures_ordered, FuturesOrdered};
+use futures::task::Poll;
 use futures_test::task::noop_waker_ref;
 
 #[test]
@@ -82,3 +83,30 @@ fn queue_never_unblocked() {
         assert!(stream.poll_next(lw).unwrap().is_pending());
     })
 }*/
+
+#[test]
+fn queued_outputs_grow_behind_slow_future() {
+    let lw = noop_waker_ref();
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let mut stream = FuturesOrdered::new();
+    stream.push(a_rx);
+
+    for i in 1..=100 {
+        let (tx, rx) = oneshot::channel::<i32>();
+        tx.send(i).unwrap();
+        stream.push(rx);
+    }
+
+    // Every later future completes, but has to wait for the first one.
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    assert_eq!(stream.queued_outputs_len(), 100);
+    assert_eq!(stream.len(), 101);
+
+    a_tx.send(0).unwrap();
+    for i in 0..=100 {
+        assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(Ok(i))));
+    }
+    assert_eq!(stream.queued_outputs_len(), 0);
+    stream.shrink_to_fit();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+}
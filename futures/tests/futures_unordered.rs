@@ -7,7 +7,7 @@ use futures::stream::{StreamExt, futures_unordered, FuturesUnordered};
 use futures::task::Poll;
 use futures_test::{assert_stream_done, assert_stream_next};
 use futures_test::future::FutureTestExt;
-use futures_test::task::noop_waker_ref;
+use futures_test::task::{new_count_waker, noop_waker_ref};
 use std::boxed::Box;
 
 #[test]
@@ -139,3 +139,52 @@ fn futures_not_moved_after_poll() {
     assert_stream_next!(stream, ());
     assert_stream_done!(stream);
 }
+
+#[test]
+fn wakes_coalesced_between_polls() {
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+    let (c_tx, c_rx) = oneshot::channel::<i32>();
+    let (d_tx, d_rx) = oneshot::channel::<i32>();
+    let (waker, count) = new_count_waker();
+
+    let mut stream = futures_unordered(vec![a_rx, b_rx, c_rx, d_rx]);
+    assert_eq!(stream.poll_next_unpin(&waker), Poll::Pending);
+
+    a_tx.send(1).unwrap();
+    b_tx.send(2).unwrap();
+    c_tx.send(3).unwrap();
+    assert_eq!(count, 1);
+
+    let mut values = Vec::new();
+    for _ in 0..3 {
+        match stream.poll_next_unpin(&waker) {
+            Poll::Ready(Some(Ok(v))) => values.push(v),
+            other => panic!("unexpected poll result: {:?}", other),
+        }
+    }
+    values.sort();
+    assert_eq!(values, vec![1, 2, 3]);
+    assert_eq!(stream.poll_next_unpin(&waker), Poll::Pending);
+    assert_eq!(count, 1);
+
+    // The next wake after a poll reaches the task again.
+    d_tx.send(4).unwrap();
+    assert_eq!(count, 2);
+    assert_eq!(stream.poll_next_unpin(&waker), Poll::Ready(Some(Ok(4))));
+}
+
+#[test]
+fn extend_non_empty_set() {
+    let mut stream: FuturesUnordered<_> = vec![future::ready(1), future::ready(2)]
+        .into_iter()
+        .collect();
+    assert_eq!(stream.len(), 2);
+
+    stream.extend(vec![future::ready(3), future::ready(4)]);
+    assert_eq!(stream.len(), 4);
+
+    let mut values = block_on(stream.collect::<Vec<_>>());
+    values.sort();
+    assert_eq!(values, vec![1, 2, 3, 4]);
+}
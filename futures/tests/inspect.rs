@@ -1,7 +1,10 @@
 #![feature(futures_api)]
 
 use futures::executor::block_on;
-use futures::future::{self, FutureExt};
+use futures::future::{self, FusedFuture, FutureExt, TryFutureExt};
+use futures::task::Poll;
+use futures_test::task::noop_waker_ref;
+use std::panic::{self, AssertUnwindSafe};
 
 #[test]
 fn smoke() {
@@ -14,3 +17,73 @@ fn smoke() {
 
     assert_eq!(counter, 40);
 }
+
+#[test]
+#[should_panic(expected = "cannot poll Inspect twice")]
+fn poll_after_completion() {
+    let mut future = future::ready(1).inspect(|_| {});
+    let lw = noop_waker_ref();
+    assert_eq!(future.poll_unpin(lw), Poll::Ready(1));
+    assert!(future.is_terminated());
+    let _ = future.poll_unpin(lw);
+}
+
+#[test]
+fn panicking_closure_terminates() {
+    let mut future = future::ready(1).inspect(|_| panic!("boom"));
+    let lw = noop_waker_ref();
+
+    let res = panic::catch_unwind(AssertUnwindSafe(|| future.poll_unpin(lw)));
+    assert!(res.is_err());
+    assert!(future.is_terminated());
+
+    let res = panic::catch_unwind(AssertUnwindSafe(|| future.poll_unpin(lw)));
+    let msg = res.unwrap_err();
+    assert_eq!(msg.downcast_ref::<&str>(), Some(&"cannot poll Inspect twice"));
+}
+
+#[test]
+fn inspect_ok() {
+    let mut seen = None;
+    let res = block_on(future::ready(Ok::<i32, i32>(1)).inspect_ok(|&x| seen = Some(x)));
+    assert_eq!(res, Ok(1));
+    assert_eq!(seen, Some(1));
+
+    let mut seen = None;
+    let res = block_on(future::ready(Err::<i32, i32>(2)).inspect_ok(|&x| seen = Some(x)));
+    assert_eq!(res, Err(2));
+    assert_eq!(seen, None);
+}
+
+#[test]
+fn inspect_err() {
+    let mut seen = None;
+    let res = block_on(future::ready(Err::<i32, i32>(2)).inspect_err(|&e| seen = Some(e)));
+    assert_eq!(res, Err(2));
+    assert_eq!(seen, Some(2));
+
+    let mut seen = None;
+    let res = block_on(future::ready(Ok::<i32, i32>(1)).inspect_err(|&e| seen = Some(e)));
+    assert_eq!(res, Ok(1));
+    assert_eq!(seen, None);
+}
+
+#[test]
+#[should_panic(expected = "cannot poll InspectOk twice")]
+fn inspect_ok_poll_after_completion() {
+    let mut future = future::ready(Ok::<i32, i32>(1)).inspect_ok(|_| {});
+    let lw = noop_waker_ref();
+    assert_eq!(future.poll_unpin(lw), Poll::Ready(Ok(1)));
+    assert!(future.is_terminated());
+    let _ = future.poll_unpin(lw);
+}
+
+#[test]
+#[should_panic(expected = "cannot poll InspectErr twice")]
+fn inspect_err_poll_after_completion() {
+    let mut future = future::ready(Err::<i32, i32>(2)).inspect_err(|_| {});
+    let lw = noop_waker_ref();
+    assert_eq!(future.poll_unpin(lw), Poll::Ready(Err(2)));
+    assert!(future.is_terminated());
+    let _ = future.poll_unpin(lw);
+}
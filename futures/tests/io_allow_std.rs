@@ -0,0 +1,33 @@
+#![feature(futures_api)]
+
+use futures::executor::block_on;
+use futures::io::{AllowStdIo, AsyncReadExt, AsyncWriteExt};
+use std::io::Cursor;
+
+#[test]
+fn write_into_vec() {
+    let mut writer = AllowStdIo::new(Vec::new());
+
+    block_on(writer.write_all(&[1, 2, 3])).unwrap();
+    block_on(writer.write_all(&[4, 5])).unwrap();
+    block_on(writer.flush()).unwrap();
+
+    assert_eq!(writer.get_ref(), &[1, 2, 3, 4, 5]);
+    writer.get_mut().clear();
+    block_on(writer.write_all(&[6])).unwrap();
+    assert_eq!(writer.into_inner(), vec![6]);
+}
+
+#[test]
+fn read_from_cursor() {
+    let mut reader = AllowStdIo::new(Cursor::new(vec![1, 2, 3, 4, 5]));
+    let mut buf = [0u8; 3];
+
+    assert_eq!(block_on(reader.read(&mut buf)).unwrap(), 3);
+    assert_eq!(buf, [1, 2, 3]);
+    assert_eq!(reader.get_ref().position(), 3);
+
+    let mut rest = Vec::new();
+    block_on(reader.read_to_end(&mut rest)).unwrap();
+    assert_eq!(rest, vec![4, 5]);
+}
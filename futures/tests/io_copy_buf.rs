@@ -0,0 +1,29 @@
+#![feature(futures_api)]
+
+use futures::executor::block_on;
+use futures::io::{self, AllowStdIo};
+use std::io::{BufReader, Cursor};
+
+#[test]
+fn copy_buf_from_buf_reader() {
+    let data: Vec<u8> = (0..100u8).collect();
+    // A small capacity forces several fill/consume rounds.
+    let mut reader = AllowStdIo::new(BufReader::with_capacity(7, Cursor::new(data.clone())));
+    let mut writer = AllowStdIo::new(Vec::new());
+
+    let n = block_on(io::copy_buf(&mut reader, &mut writer)).unwrap();
+
+    assert_eq!(n, 100);
+    assert_eq!(writer.into_inner(), data);
+}
+
+#[test]
+fn copy_buf_empty() {
+    let mut reader: &[u8] = &[];
+    let mut writer = AllowStdIo::new(Vec::new());
+
+    let n = block_on(io::copy_buf(&mut reader, &mut writer)).unwrap();
+
+    assert_eq!(n, 0);
+    assert!(writer.into_inner().is_empty());
+}
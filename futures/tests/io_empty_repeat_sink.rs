@@ -0,0 +1,39 @@
+#![feature(futures_api)]
+
+use futures::executor::block_on;
+use futures::io::{self, AsyncBufRead, AsyncReadExt, AsyncWriteExt};
+use futures::task::Poll;
+use futures_test::task::noop_waker_ref;
+
+#[test]
+fn repeat_fills_buffer() {
+    let mut reader = io::repeat(b'x');
+    let mut buf = [0; 5];
+    block_on(reader.read_exact(&mut buf)).unwrap();
+    assert_eq!(&buf, b"xxxxx");
+
+    let mut buf = [0; 3];
+    assert_eq!(block_on(reader.read(&mut buf)).unwrap(), 3);
+    assert_eq!(&buf, b"xxx");
+}
+
+#[test]
+fn sink_accepts_everything() {
+    let mut writer = io::sink();
+    block_on(writer.write_all(b"hello world")).unwrap();
+    block_on(writer.flush()).unwrap();
+    block_on(writer.close()).unwrap();
+}
+
+#[test]
+fn empty_is_at_eof() {
+    let mut reader = io::empty();
+    let mut buf = Vec::new();
+    block_on(reader.read_to_end(&mut buf)).unwrap();
+    assert!(buf.is_empty());
+
+    match reader.poll_fill_buf(noop_waker_ref()) {
+        Poll::Ready(Ok(buf)) => assert!(buf.is_empty()),
+        _ => panic!("empty reader should be ready with an empty buffer"),
+    }
+}
@@ -0,0 +1,123 @@
+#![feature(futures_api)]
+
+use futures::executor::block_on;
+use futures::io::{self, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use futures::task::{Waker, Poll};
+use futures_test::io::{MockReader, MockWriter};
+
+#[test]
+fn read_exact_through_short_reads() {
+    let mut reader = MockReader::new(vec![
+        Poll::Pending,
+        Poll::Ready(Ok(vec![1, 2])),
+        Poll::Pending,
+        Poll::Ready(Ok(vec![3, 4, 5])),
+    ]);
+    let mut buf = [0u8; 4];
+
+    block_on(reader.read_exact(&mut buf)).unwrap();
+    assert_eq!(buf, [1, 2, 3, 4]);
+
+    let mut rest = [0u8; 1];
+    block_on(reader.read_exact(&mut rest)).unwrap();
+    assert_eq!(rest, [5]);
+    assert!(reader.is_done());
+}
+
+#[test]
+fn read_exact_hits_eof() {
+    let mut reader = MockReader::new(vec![Poll::Ready(Ok(vec![1, 2]))]);
+    let mut buf = [0u8; 4];
+
+    let err = block_on(reader.read_exact(&mut buf)).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn read_exact_surfaces_errors() {
+    let mut reader = MockReader::new(vec![
+        Poll::Ready(Ok(vec![1])),
+        Poll::Ready(Err(io::ErrorKind::BrokenPipe.into())),
+    ]);
+    let mut buf = [0u8; 4];
+
+    let err = block_on(reader.read_exact(&mut buf)).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+}
+
+#[test]
+fn write_all_through_short_writes() {
+    let mut writer = MockWriter::new(vec![
+        Poll::Ready(Ok(1)),
+        Poll::Pending,
+        Poll::Ready(Ok(2)),
+    ]);
+
+    block_on(writer.write_all(&[1, 2, 3, 4, 5])).unwrap();
+    assert_eq!(writer.written(), &[1, 2, 3, 4, 5]);
+    assert!(writer.is_done());
+}
+
+#[test]
+fn write_all_zero_write() {
+    let mut writer = MockWriter::new(vec![Poll::Ready(Ok(0))]);
+
+    let err = block_on(writer.write_all(&[1, 2])).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+}
+
+struct FlushRecorder {
+    inner: MockWriter,
+    // The number of bytes written at the time of each flush
+    flushes: Vec<usize>,
+}
+
+impl AsyncWrite for FlushRecorder {
+    fn poll_write(&mut self, waker: &Waker, buf: &[u8])
+        -> Poll<io::Result<usize>>
+    {
+        self.inner.poll_write(waker, buf)
+    }
+
+    fn poll_flush(&mut self, waker: &Waker) -> Poll<io::Result<()>> {
+        self.flushes.push(self.inner.written().len());
+        self.inner.poll_flush(waker)
+    }
+
+    fn poll_close(&mut self, waker: &Waker) -> Poll<io::Result<()>> {
+        self.inner.poll_close(waker)
+    }
+}
+
+#[test]
+fn write_all_and_flush_through_short_writes() {
+    let mut writer = FlushRecorder {
+        inner: MockWriter::new(vec![
+            Poll::Ready(Ok(2)),
+            Poll::Pending,
+            Poll::Ready(Ok(1)),
+            Poll::Ready(Ok(2)),
+        ]),
+        flushes: Vec::new(),
+    };
+
+    block_on(writer.write_all_and_flush(&[1, 2, 3, 4, 5])).unwrap();
+    assert_eq!(writer.inner.written(), &[1, 2, 3, 4, 5]);
+    assert!(writer.inner.is_done());
+    assert_eq!(writer.flushes, vec![5]);
+}
+
+#[test]
+fn write_all_and_flush_skips_flush_on_error() {
+    let mut writer = FlushRecorder {
+        inner: MockWriter::new(vec![
+            Poll::Ready(Ok(1)),
+            Poll::Ready(Err(io::ErrorKind::BrokenPipe.into())),
+        ]),
+        flushes: Vec::new(),
+    };
+
+    let err = block_on(writer.write_all_and_flush(&[1, 2])).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+    assert!(writer.flushes.is_empty());
+}
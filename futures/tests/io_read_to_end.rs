@@ -0,0 +1,60 @@
+#![feature(futures_api)]
+
+use futures::executor::block_on;
+use futures::io::{AsyncRead, AsyncReadExt};
+use futures::task::{Poll, Waker};
+use std::io::{self, Cursor};
+
+/// Counts the calls to `poll_read` on the wrapped reader.
+struct CountReads<R> {
+    inner: R,
+    reads: usize,
+}
+
+impl<R: AsyncRead> AsyncRead for CountReads<R> {
+    fn poll_read(&mut self, waker: &Waker, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        self.reads += 1;
+        self.inner.poll_read(waker, buf)
+    }
+}
+
+fn data(len: usize) -> Vec<u8> {
+    (0..len).map(|i| i as u8).collect()
+}
+
+#[test]
+fn read_to_end_large() {
+    let expected = data(1024 * 1024);
+    let mut reader = CountReads { inner: Cursor::new(expected.clone()), reads: 0 };
+    let mut out = Vec::new();
+
+    block_on(reader.read_to_end(&mut out)).unwrap();
+
+    assert_eq!(out, expected);
+    // The reads grow along with the buffer, so 1 MiB only takes a handful.
+    assert!(reader.reads <= 20, "too many reads: {}", reader.reads);
+}
+
+#[test]
+fn read_to_end_appends() {
+    let mut reader = Cursor::new(vec![3, 4]);
+    let mut out = vec![1, 2];
+
+    block_on(reader.read_to_end(&mut out)).unwrap();
+
+    assert_eq!(out, vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn read_to_end_with_capacity() {
+    let expected = data(4096);
+    let mut reader = CountReads { inner: Cursor::new(expected.clone()), reads: 0 };
+    let mut out = Vec::new();
+
+    block_on(reader.read_to_end_with_capacity(&mut out, 4096)).unwrap();
+
+    assert_eq!(out, expected);
+    // One read fills the reservation and one more read observes EOF, plus
+    // possibly one on the extra space reserved after the buffer filled up.
+    assert!(reader.reads <= 3, "too many reads: {}", reader.reads);
+}
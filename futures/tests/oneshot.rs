@@ -2,7 +2,9 @@
 
 use futures::channel::oneshot;
 use futures::future::{FutureExt, TryFutureExt};
+use futures::task::Poll;
 use futures_test::future::FutureTestExt;
+use futures_test::task::new_count_waker;
 use std::sync::mpsc;
 use std::thread;
 
@@ -66,3 +68,25 @@ fn oneshot_drop_rx() {
     drop(rx);
     assert_eq!(Err(2), tx.send(2));
 }
+
+#[test]
+fn oneshot_canceled_then_send() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    assert!(!tx.is_canceled());
+    drop(rx);
+    assert!(tx.is_canceled());
+    assert_eq!(Err(3), tx.send(3));
+}
+
+#[test]
+fn oneshot_drop_rx_wakes_poll_cancel() {
+    let (mut tx, rx) = oneshot::channel::<i32>();
+    let (waker, count) = new_count_waker();
+
+    assert_eq!(Poll::Pending, tx.poll_cancel(&waker));
+    assert_eq!(0, count.get());
+
+    drop(rx);
+    assert_eq!(1, count.get());
+    assert_eq!(Poll::Ready(()), tx.poll_cancel(&waker));
+}
@@ -0,0 +1,43 @@
+#![feature(futures_api)]
+
+use futures::stream::{self, FusedStream, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_waker_ref;
+use std::pin::Pin;
+
+#[test]
+fn is_terminated_across_peek() {
+    let lw = noop_waker_ref();
+    let mut stream = stream::iter(vec![1]).peekable();
+
+    assert!(!stream.is_terminated());
+    assert_eq!(Pin::new(&mut stream).peek(lw), Poll::Ready(Some(&1)));
+    assert!(!stream.is_terminated());
+
+    // Peeking again returns the cached item without polling the stream.
+    assert_eq!(Pin::new(&mut stream).peek(lw), Poll::Ready(Some(&1)));
+    assert!(!stream.is_terminated());
+
+    // The inner stream hasn't reported its end yet.
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert!(!stream.is_terminated());
+
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+    assert!(stream.is_terminated());
+    assert_eq!(Pin::new(&mut stream).peek(lw), Poll::Ready(None));
+    assert!(stream.is_terminated());
+}
+
+#[test]
+fn is_terminated_after_peeking_end() {
+    let lw = noop_waker_ref();
+    let mut stream = stream::iter(vec![1]).peekable();
+
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert!(!stream.is_terminated());
+
+    assert_eq!(Pin::new(&mut stream).peek(lw), Poll::Ready(None));
+    assert!(stream.is_terminated());
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+    assert!(stream.is_terminated());
+}
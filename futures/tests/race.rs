@@ -0,0 +1,44 @@
+#![feature(futures_api)]
+
+use futures::executor::block_on;
+use futures::future::{self, race, Either, FusedFuture, FutureExt};
+use futures::task::Poll;
+use futures_test::future::FutureTestExt;
+use futures_test::task::noop_waker_ref;
+use std::cell::Cell;
+
+struct SetOnDrop<'a>(&'a Cell<bool>);
+
+impl Drop for SetOnDrop<'_> {
+    fn drop(&mut self) {
+        self.0.set(true);
+    }
+}
+
+#[test]
+fn ready_beats_pending() {
+    let dropped = Cell::new(false);
+    let guard = SetOnDrop(&dropped);
+    let pending = future::empty::<i32>().map(move |x| { let _ = &guard; x });
+
+    let mut fut = race(pending, future::ready("ready"));
+    assert_eq!(fut.poll_unpin(noop_waker_ref()), Poll::Ready(Either::Right("ready")));
+    // The losing future is dropped as soon as the race is decided.
+    assert!(dropped.get());
+    assert!(fut.is_terminated());
+}
+
+#[test]
+fn first_future_wins_ties() {
+    let fut = race(future::ready(1), future::ready(2));
+    assert_eq!(block_on(fut), Either::Left(1));
+}
+
+#[test]
+fn waits_for_either_future() {
+    let fut = race(future::ready(1).pending_once(), future::ready(2).pending_once());
+    assert_eq!(block_on(fut), Either::Left(1));
+
+    let fut = race(future::empty::<i32>(), future::ready(2).pending_once());
+    assert_eq!(block_on(fut), Either::Right(2));
+}
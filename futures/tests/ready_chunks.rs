@@ -0,0 +1,41 @@
+#![feature(futures_api)]
+
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::stream::{self, FusedStream, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_waker_ref;
+
+#[test]
+fn yields_ready_items_without_waiting() {
+    let (tx, rx) = mpsc::unbounded();
+    let lw = noop_waker_ref();
+    let mut stream = rx.ready_chunks(10);
+
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Pending);
+
+    tx.unbounded_send(1).unwrap();
+    tx.unbounded_send(2).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(vec![1, 2])));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Pending);
+
+    tx.unbounded_send(3).unwrap();
+    drop(tx);
+    assert!(!stream.is_terminated());
+    // The final partial chunk is yielded once the channel is closed.
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(vec![3])));
+    assert!(stream.is_terminated());
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn respects_capacity() {
+    let chunks = block_on(stream::iter(1..=7).ready_chunks(3).collect::<Vec<_>>());
+    assert_eq!(chunks, vec![vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+}
+
+#[test]
+#[should_panic]
+fn zero_capacity() {
+    let _ = stream::iter(1..=3).ready_chunks(0);
+}
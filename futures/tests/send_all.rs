@@ -0,0 +1,82 @@
+#![feature(futures_api)]
+
+use futures::executor::block_on;
+use futures::sink::{Sink, SinkExt};
+use futures::stream;
+use futures::task::{Waker, Poll};
+use std::pin::Pin;
+
+#[derive(Default)]
+struct RecordingSink {
+    sent: Vec<i32>,
+    flushed: Vec<i32>,
+    fail_at: Option<usize>,
+    closed: bool,
+}
+
+impl Sink for RecordingSink {
+    type SinkItem = i32;
+    type SinkError = &'static str;
+
+    fn poll_ready(
+        self: Pin<&mut Self>,
+        _: &Waker,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(
+        mut self: Pin<&mut Self>,
+        item: i32,
+    ) -> Result<(), Self::SinkError> {
+        if self.fail_at == Some(self.sent.len()) {
+            return Err("sink error");
+        }
+        self.sent.push(item);
+        Ok(())
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        _: &Waker,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        let this = &mut *self;
+        this.flushed = this.sent.clone();
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        _: &Waker,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.closed = true;
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[test]
+fn send_all_returns_sink_error() {
+    let mut sink = RecordingSink { fail_at: Some(1), ..Default::default() };
+    let mut stream = stream::iter(1..=3);
+
+    assert_eq!(block_on(sink.send_all(&mut stream)), Err("sink error"));
+    assert_eq!(sink.sent, vec![1]);
+    // The error is returned right away, without flushing the sink.
+    assert!(sink.flushed.is_empty());
+    assert!(!sink.closed);
+}
+
+#[test]
+fn send_all_flushes_once_stream_ends() {
+    let mut sink = RecordingSink::default();
+    let mut stream = stream::iter(1..=3);
+
+    assert_eq!(block_on(sink.send_all(&mut stream)), Ok(()));
+    assert_eq!(sink.flushed, vec![1, 2, 3]);
+    // The sink is only flushed, not closed.
+    assert!(!sink.closed);
+
+    let mut vec = Vec::new();
+    block_on(vec.send_all(&mut stream::iter(1..=3))).unwrap();
+    assert_eq!(vec, vec![1, 2, 3]);
+}
@@ -0,0 +1,51 @@
+#![feature(futures_api)]
+
+use futures::channel::mpsc;
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::task::{new_count_waker, noop_waker_ref};
+
+#[test]
+fn interleaved_consumption() {
+    let lw = noop_waker_ref();
+    let stream = stream::iter(vec![Ok(1), Err("a"), Err("b"), Ok(2), Ok(3)]);
+    let (mut oks, mut errs) = stream.split_results();
+
+    assert_eq!(oks.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert_eq!(errs.poll_next_unpin(lw), Poll::Ready(Some("a")));
+    // "b" has to be buffered for the error half to get to 2.
+    assert_eq!(oks.poll_next_unpin(lw), Poll::Ready(Some(2)));
+    assert_eq!(errs.poll_next_unpin(lw), Poll::Ready(Some("b")));
+    // 3 has to be buffered for the error half to see the end of the stream.
+    assert_eq!(errs.poll_next_unpin(lw), Poll::Ready(None));
+    assert_eq!(oks.poll_next_unpin(lw), Poll::Ready(Some(3)));
+    assert_eq!(oks.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn wakes_other_half() {
+    let (tx, rx) = mpsc::unbounded::<Result<i32, &str>>();
+    let (mut oks, mut errs) = rx.split_results();
+
+    let (err_lw, err_count) = new_count_waker();
+    let ok_lw = noop_waker_ref();
+
+    assert_eq!(errs.poll_next_unpin(&err_lw), Poll::Pending);
+    assert_eq!(oks.poll_next_unpin(ok_lw), Poll::Pending);
+
+    // The error half is woken directly, even though the `Ok` half polled
+    // the channel last and isn't polled again.
+    tx.unbounded_send(Err("a")).unwrap();
+    assert_eq!(err_count, 1);
+    assert_eq!(errs.poll_next_unpin(&err_lw), Poll::Ready(Some("a")));
+
+    // The error half keeps getting woken once the `Ok` half is gone.
+    drop(oks);
+    assert_eq!(errs.poll_next_unpin(&err_lw), Poll::Pending);
+    tx.unbounded_send(Err("b")).unwrap();
+    assert_eq!(err_count, 2);
+    assert_eq!(errs.poll_next_unpin(&err_lw), Poll::Ready(Some("b")));
+
+    drop(tx);
+    assert_eq!(errs.poll_next_unpin(&err_lw), Poll::Ready(None));
+}
@@ -4,8 +4,14 @@ extern crate futures;
 extern crate futures_util;
 
 use futures::executor::block_on;
-use futures::stream;
-use futures_util::StreamExt;
+use futures::future::{self, FusedFuture};
+use futures::stream::{self, FusedStream};
+use futures::task::Poll;
+use futures_test::future::FutureTestExt;
+use futures_test::stream::StreamTestExt;
+use futures_test::task::noop_waker_ref;
+use futures_util::{FutureExt, StreamExt};
+use std::cell::Cell;
 
 #[test]
 fn select() {
@@ -20,3 +26,592 @@ fn select() {
     select_and_compare(vec![1, 2, 3], vec![4, 5], vec![1, 4, 2, 5, 3]);
     select_and_compare(vec![1, 2], vec![4, 5, 6], vec![1, 4, 2, 5, 6]);
 }
+
+#[test]
+fn merge() {
+    use futures::merge;
+
+    // Every stream gets an equal share of the polls.
+    let a = stream::repeat('a');
+    let b = stream::repeat('b');
+    let c = stream::repeat('c');
+    let vec = block_on(merge!(a, b, c).take(9).collect::<Vec<_>>());
+    for ch in &['a', 'b', 'c'] {
+        assert_eq!(vec.iter().filter(|x| *x == ch).count(), 3);
+    }
+    assert_eq!(vec, vec!['a', 'b', 'c', 'a', 'b', 'c', 'a', 'b', 'c']);
+
+    let a = stream::repeat('a');
+    let b = stream::repeat('b');
+    let c = stream::repeat('c');
+    let d = stream::repeat('d');
+    let vec = block_on(merge!(a, b, c, d).take(8).collect::<Vec<_>>());
+    assert_eq!(vec, vec!['a', 'b', 'c', 'd', 'a', 'b', 'c', 'd']);
+
+    // The sources are interleaved in turn and run until each is exhausted.
+    let a = stream::iter(vec![1, 4]);
+    let b = stream::iter(vec![2, 5]);
+    let c = stream::iter(vec![3, 6, 7, 8]);
+    let vec = block_on(merge!(a, b, c).collect::<Vec<_>>());
+    assert_eq!(vec, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+
+    // More than five streams are split into two balanced halves.
+    let merged = merge!(
+        stream::repeat(0), stream::repeat(1), stream::repeat(2),
+        stream::repeat(3), stream::repeat(4), stream::repeat(5),
+    );
+    let vec = block_on(merged.take(12).collect::<Vec<_>>());
+    for i in 0..6 {
+        assert_eq!(vec.iter().filter(|x| **x == i).count(), 2);
+    }
+
+    let merged = merge!(
+        stream::iter(vec![1]), stream::iter(vec![2]), stream::iter(vec![3]),
+        stream::iter(vec![4]), stream::iter(vec![5]), stream::iter(vec![6]),
+        stream::iter(vec![7]),
+    );
+    let mut vec = block_on(merged.collect::<Vec<_>>());
+    vec.sort();
+    assert_eq!(vec, (1..=7).collect::<Vec<_>>());
+}
+
+#[test]
+fn zip_longest() {
+    use futures::stream::EitherOrBoth::{self, Both, Left, Right};
+
+    fn zip_and_compare(a: Vec<u32>, b: Vec<u32>, expected: Vec<EitherOrBoth<u32, u32>>) {
+        let a = stream::iter(a);
+        let b = stream::iter(b);
+        let vec = block_on(a.zip_longest(b).collect::<Vec<_>>());
+        assert_eq!(vec, expected);
+    }
+
+    zip_and_compare(vec![1, 2, 3], vec![4], vec![Both(1, 4), Left(2), Left(3)]);
+    zip_and_compare(vec![1], vec![4, 5, 6], vec![Both(1, 4), Right(5), Right(6)]);
+    zip_and_compare(vec![1, 2], vec![4, 5], vec![Both(1, 4), Both(2, 5)]);
+    zip_and_compare(vec![], vec![4], vec![Right(4)]);
+    zip_and_compare(vec![], vec![], vec![]);
+}
+
+#[test]
+fn chunks_exact() {
+    let mut stream = stream::iter(1..=7).chunks_exact(3);
+    let chunks = block_on(stream.by_ref().collect::<Vec<_>>());
+    assert_eq!(chunks, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    assert_eq!(stream.into_remainder(), vec![7]);
+
+    let mut stream = stream::iter(1..=6).chunks_exact(3);
+    let chunks = block_on(stream.by_ref().collect::<Vec<_>>());
+    assert_eq!(chunks, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    assert!(stream.into_remainder().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Collect polled after completion")]
+fn collect_polled_after_completion() {
+    let lw = noop_waker_ref();
+    let mut fut = stream::iter(vec![1, 2]).collect::<Vec<_>>();
+    assert!(fut.poll_unpin(lw).is_ready());
+    let _ = fut.poll_unpin(lw);
+}
+
+#[test]
+#[should_panic(expected = "Fold polled after completion")]
+fn fold_polled_after_completion() {
+    let lw = noop_waker_ref();
+    let mut fut = stream::iter(vec![1, 2]).fold(0, |a, b| future::ready(a + b));
+    assert!(fut.poll_unpin(lw).is_ready());
+    let _ = fut.poll_unpin(lw);
+}
+
+#[test]
+#[should_panic(expected = "ForEach polled after completion")]
+fn for_each_polled_after_completion() {
+    let lw = noop_waker_ref();
+    let mut fut = stream::iter(vec![1, 2]).for_each(|_| future::ready(()));
+    assert!(fut.poll_unpin(lw).is_ready());
+    let _ = fut.poll_unpin(lw);
+}
+
+#[test]
+fn consumers_are_terminated_after_completion() {
+    // `Once` doesn't implement `FusedStream`, the futures track their own state.
+    let lw = noop_waker_ref();
+
+    let mut fut = stream::once(future::ready(1)).collect::<Vec<_>>();
+    assert!(!fut.is_terminated());
+    assert_eq!(fut.poll_unpin(lw), Poll::Ready(vec![1]));
+    assert!(fut.is_terminated());
+
+    let mut fut = stream::once(future::ready(1)).fold(0, |a, b| future::ready(a + b));
+    assert!(!fut.is_terminated());
+    assert_eq!(fut.poll_unpin(lw), Poll::Ready(1));
+    assert!(fut.is_terminated());
+
+    let mut fut = stream::once(future::ready(1)).for_each(|_| future::ready(()));
+    assert!(!fut.is_terminated());
+    assert_eq!(fut.poll_unpin(lw), Poll::Ready(()));
+    assert!(fut.is_terminated());
+}
+
+#[test]
+fn iter_is_terminated() {
+    let lw = noop_waker_ref();
+    let mut stream = stream::iter(vec![1, 2]);
+
+    assert!(!stream.is_terminated());
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(2)));
+    assert!(!stream.is_terminated());
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+    assert!(stream.is_terminated());
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn repeat_is_never_terminated() {
+    let lw = noop_waker_ref();
+    let mut stream = stream::repeat(1);
+
+    for _ in 0..10 {
+        assert!(!stream.is_terminated());
+        assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    }
+    assert!(!stream.is_terminated());
+
+    assert_eq!(block_on(stream::repeat(1).take(3).collect::<Vec<_>>()), vec![1, 1, 1]);
+}
+
+#[test]
+fn with_position() {
+    use futures::stream::Position::{self, First, Last, Middle, Only};
+
+    fn positions(items: Vec<u32>) -> Vec<(Position, u32)> {
+        block_on(stream::iter(items).with_position().collect::<Vec<_>>())
+    }
+
+    assert_eq!(positions(vec![]), vec![]);
+    assert_eq!(positions(vec![1]), vec![(Only, 1)]);
+    assert_eq!(positions(vec![1, 2]), vec![(First, 1), (Last, 2)]);
+    assert_eq!(positions(vec![1, 2, 3]), vec![(First, 1), (Middle, 2), (Last, 3)]);
+}
+
+#[test]
+fn inspect_done() {
+    use std::cell::RefCell;
+
+    let calls = RefCell::new(Vec::new());
+
+    let stream = stream::iter(1..=3).inspect_done(|done| calls.borrow_mut().push(done));
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![1, 2, 3]);
+    assert_eq!(*calls.borrow(), vec![true]);
+
+    calls.borrow_mut().clear();
+    let mut stream = stream::iter(1..=3).inspect_done(|done| calls.borrow_mut().push(done));
+    assert_eq!(block_on(stream.next()), Some(1));
+    assert!(calls.borrow().is_empty());
+    drop(stream);
+    assert_eq!(*calls.borrow(), vec![false]);
+}
+
+#[test]
+fn take_into_inner() {
+    let mut stream = stream::iter(1..=5).take(2);
+    assert!(!stream.is_terminated());
+    assert_eq!(block_on(stream.by_ref().collect::<Vec<_>>()), vec![1, 2]);
+    assert!(stream.is_terminated());
+
+    let mut inner = stream.into_inner();
+    assert_eq!(block_on(inner.next()), Some(3));
+
+    let mut stream = stream::iter(1..=1).take(3);
+    assert_eq!(block_on(stream.by_ref().collect::<Vec<_>>()), vec![1]);
+    assert!(stream.is_terminated());
+}
+
+#[test]
+fn select_one_sided_readiness() {
+    // `b` is only ready on every third poll, while `a` is always ready.
+    let a = stream::repeat('a');
+    let mut polls = 0;
+    let b = stream::poll_fn(move |lw| {
+        polls += 1;
+        if polls % 3 == 0 {
+            Poll::Ready(Some('b'))
+        } else {
+            lw.wake();
+            Poll::Pending
+        }
+    });
+
+    // `b` gets the first shot after `a` yields, so each of its items is
+    // emitted as soon as it is ready.
+    let vec = block_on(a.select(b).take(12).collect::<Vec<_>>());
+    assert_eq!(vec, "aaabaaabaaab".chars().collect::<Vec<_>>());
+}
+
+#[test]
+fn flatten_skips_empty_inner_streams() {
+    let lw = noop_waker_ref();
+    let mut stream = stream::iter(vec![
+        stream::iter(vec![]),
+        stream::iter(vec![1]),
+        stream::iter(vec![]),
+        stream::iter(vec![]),
+        stream::iter(vec![2, 3]),
+        stream::iter(vec![]),
+    ]).flatten();
+
+    assert!(!stream.is_terminated());
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(2)));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(3)));
+    assert!(!stream.is_terminated());
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+    assert!(stream.is_terminated());
+
+    let empty = stream::iter((0..3).map(|_| stream::iter(Vec::<i32>::new()))).flatten();
+    assert_eq!(block_on(empty.collect::<Vec<_>>()), vec![]);
+}
+
+#[test]
+fn then_repolls_pending_future() {
+    let lw = noop_waker_ref();
+    let pulled = Cell::new(0);
+    let mut stream = stream::iter(1..=2)
+        .inspect(|_| pulled.set(pulled.get() + 1))
+        .then(|x| future::ready(x * 10).pending_once());
+
+    // The first future is pending, and it is polled again instead of pulling
+    // the next item from the stream.
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Pending);
+    assert_eq!(pulled.get(), 1);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(10)));
+    assert_eq!(pulled.get(), 1);
+
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Pending);
+    assert_eq!(pulled.get(), 2);
+    assert!(!stream.is_terminated());
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(20)));
+
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+    assert!(stream.is_terminated());
+}
+
+#[test]
+fn filter_resumes_pending_predicate() {
+    let lw = noop_waker_ref();
+    let pulled = Cell::new(0);
+    let mut stream = stream::iter(1..=2)
+        .inspect(|_| pulled.set(pulled.get() + 1))
+        .filter(|_| future::ready(true).pending_once());
+
+    // The predicate for the first item is pending: the item must be kept and
+    // no further item pulled from the stream.
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Pending);
+    assert_eq!(pulled.get(), 1);
+    assert!(!stream.is_terminated());
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert_eq!(pulled.get(), 1);
+
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Pending);
+    assert_eq!(pulled.get(), 2);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(2)));
+
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+    assert!(stream.is_terminated());
+}
+
+#[test]
+fn fold_while_stops_at_threshold() {
+    use futures::stream::ControlFlow;
+
+    let mut stream = stream::iter(1..=10);
+    {
+        let mut fut = (&mut stream).fold_while(0, |acc, x| {
+            let acc = acc + x;
+            future::ready(if acc > 10 {
+                ControlFlow::Break(acc)
+            } else {
+                ControlFlow::Continue(acc)
+            })
+        });
+        assert!(!fut.is_terminated());
+        assert_eq!(fut.poll_unpin(noop_waker_ref()), Poll::Ready(15));
+        assert!(fut.is_terminated());
+    }
+    // The items after the one that broke the fold are left in the stream.
+    assert_eq!(block_on(stream.next()), Some(6));
+
+    let sum = stream::iter(1..=3)
+        .fold_while(0, |acc, x| future::ready(ControlFlow::Continue(acc + x)));
+    assert_eq!(block_on(sum), 6);
+}
+
+#[test]
+fn collect_extend_targets() {
+    use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+    let map: BTreeMap<_, _> = block_on(stream::iter(vec![(2, "b"), (1, "a")]).collect());
+    assert_eq!(map.into_iter().collect::<Vec<_>>(), vec![(1, "a"), (2, "b")]);
+
+    let map: HashMap<_, _> = block_on(stream::iter(vec![(1, "a"), (1, "b")]).collect());
+    assert_eq!(map.len(), 1);
+    assert_eq!(map[&1], "b");
+
+    let set: BTreeSet<_> = block_on(stream::iter(vec![3, 1, 3, 2]).collect());
+    assert_eq!(set.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+
+    let deque: VecDeque<_> = block_on(stream::iter(1..=3).collect());
+    assert_eq!(deque, VecDeque::from(vec![1, 2, 3]));
+
+    let string: String = block_on(stream::iter("hello".chars()).collect());
+    assert_eq!(string, "hello");
+    let string: String = block_on(stream::iter(vec!["hel", "lo"]).collect());
+    assert_eq!(string, "hello");
+    let string: String = block_on(stream::iter(vec![String::from("hel"), String::from("lo")]).collect());
+    assert_eq!(string, "hello");
+
+    let empty: Vec<i32> = block_on(stream::empty().collect());
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn skip_into_inner() {
+    // The stream ends while skipping.
+    let mut stream = stream::iter(1..=3).skip(5);
+    assert!(!stream.is_terminated());
+    assert_eq!(block_on(stream.by_ref().collect::<Vec<_>>()), vec![]);
+    assert!(stream.is_terminated());
+
+    let mut stream = stream::iter(1..=3).skip(1);
+    assert_eq!(block_on(stream.next()), Some(2));
+    assert!(!stream.is_terminated());
+    let mut inner = stream.into_inner();
+    assert_eq!(block_on(inner.next()), Some(3));
+
+    let mut stream = stream::iter(1..=3).skip(1);
+    assert_eq!(block_on(stream.by_ref().collect::<Vec<_>>()), vec![2, 3]);
+    assert!(stream.is_terminated());
+    assert!(stream.get_mut().is_terminated());
+    assert_eq!(block_on(stream.into_inner().next()), None);
+}
+
+#[test]
+fn drain() {
+    use futures::channel::mpsc;
+    use futures_test::task::new_count_waker;
+
+    let count = Cell::new(0);
+    block_on(stream::iter(1..=100).inspect(|_| count.set(count.get() + 1)).drain());
+    assert_eq!(count.get(), 100);
+
+    let (tx, rx) = mpsc::unbounded::<i32>();
+    let (waker, wake_count) = new_count_waker();
+    let mut fut = rx.drain();
+
+    tx.unbounded_send(1).unwrap();
+    assert_eq!(fut.poll_unpin(&waker), Poll::Pending);
+    assert_eq!(wake_count, 0);
+    assert!(!fut.is_terminated());
+
+    tx.unbounded_send(2).unwrap();
+    assert_eq!(wake_count, 1);
+    drop(tx);
+    assert_eq!(fut.poll_unpin(&waker), Poll::Ready(()));
+    assert!(fut.is_terminated());
+}
+
+#[test]
+fn buffer_zero_limit_is_unbounded() {
+    use futures::channel::oneshot;
+    use futures_util::TryStreamExt;
+
+    let lw = noop_waker_ref();
+
+    // All futures are started at once, so the last one can complete first.
+    let (txs, rxs): (Vec<_>, Vec<_>) = (0..3).map(|_| oneshot::channel::<i32>()).unzip();
+    let mut s = stream::iter(rxs).buffer_unordered(0);
+    assert_eq!(s.poll_next_unpin(lw), Poll::Pending);
+    for (i, tx) in txs.into_iter().enumerate().rev() {
+        tx.send(i as i32).unwrap();
+    }
+    assert_eq!(block_on(s.collect::<Vec<_>>()), vec![Ok(2), Ok(1), Ok(0)]);
+
+    let (txs, rxs): (Vec<_>, Vec<_>) = (0..3).map(|_| oneshot::channel::<i32>()).unzip();
+    let mut s = stream::iter(rxs).buffered(0);
+    assert_eq!(s.poll_next_unpin(lw), Poll::Pending);
+    for (i, tx) in txs.into_iter().enumerate().rev() {
+        tx.send(i as i32).unwrap();
+    }
+    assert_eq!(block_on(s.collect::<Vec<_>>()), vec![Ok(0), Ok(1), Ok(2)]);
+
+    let (txs, rxs): (Vec<_>, Vec<_>) = (0..3).map(|_| oneshot::channel::<i32>()).unzip();
+    let mut s = stream::iter(rxs.into_iter().map(Ok::<_, oneshot::Canceled>)).try_buffer_unordered(0);
+    assert_eq!(s.poll_next_unpin(lw), Poll::Pending);
+    for (i, tx) in txs.into_iter().enumerate().rev() {
+        tx.send(i as i32).unwrap();
+    }
+    assert_eq!(block_on(s.collect::<Vec<_>>()), vec![Ok(2), Ok(1), Ok(0)]);
+}
+
+#[test]
+fn map_composed() {
+    let mut calls = Vec::new();
+    let mut stream = stream::iter(1..=3).map_composed(
+        |x| x + 3,
+        |x| { calls.push(x); x * 2 },
+    );
+    assert_eq!(block_on(stream.by_ref().collect::<Vec<_>>()), vec![8, 10, 12]);
+    assert!(stream.is_terminated());
+    drop(stream);
+    assert_eq!(calls, vec![4, 5, 6]);
+}
+
+#[test]
+fn count_if() {
+    assert_eq!(block_on(stream::iter(1..=10).count_if(|x| x % 2 == 0)), 5);
+    assert_eq!(block_on(stream::iter(Vec::<i32>::new()).count_if(|_| true)), 0);
+
+    let mut fut = stream::iter(1..=3).interleave_pending().count_if(|&x| x > 1);
+    let lw = noop_waker_ref();
+    assert_eq!(fut.poll_unpin(lw), Poll::Pending);
+    assert!(!fut.is_terminated());
+    loop {
+        if let Poll::Ready(count) = fut.poll_unpin(lw) {
+            assert_eq!(count, 2);
+            break;
+        }
+    }
+    assert!(fut.is_terminated());
+}
+
+#[test]
+fn replicate() {
+    let mut stream = stream::replicate(String::from("a"), 3);
+    assert_eq!(stream.size_hint(), (3, Some(3)));
+    assert!(!stream.is_terminated());
+
+    let lw = noop_waker_ref();
+    for remaining in (0..3).rev() {
+        assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(String::from("a"))));
+        assert_eq!(stream.size_hint(), (remaining, Some(remaining)));
+    }
+    assert!(stream.is_terminated());
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+
+    let mut empty = stream::replicate(1, 0);
+    assert!(empty.is_terminated());
+    assert_eq!(empty.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn zip3_and_zip4() {
+    let mut zipped = stream::iter(1..=4)
+        .zip3(stream::iter(vec!['a', 'b']).interleave_pending(), stream::iter(10..=12));
+    let lw = noop_waker_ref();
+    let mut items = Vec::new();
+    loop {
+        match zipped.poll_next_unpin(lw) {
+            Poll::Ready(Some(item)) => items.push(item),
+            Poll::Ready(None) => break,
+            Poll::Pending => assert!(!zipped.is_terminated()),
+        }
+    }
+    assert_eq!(items, vec![(1, 'a', 10), (2, 'b', 11)]);
+    assert!(zipped.is_terminated());
+    assert_eq!(zipped.poll_next_unpin(lw), Poll::Ready(None));
+
+    let zipped = stream::iter(1..=3).zip4(
+        stream::iter(vec!["a", "b", "c"]),
+        stream::iter(5..=10),
+        stream::iter(vec![true, false]),
+    );
+    assert_eq!(
+        block_on(zipped.collect::<Vec<_>>()),
+        vec![(1, "a", 5, true), (2, "b", 6, false)],
+    );
+}
+
+#[test]
+fn flatten_non_fused_inner_streams() {
+    // `Once` doesn't implement `FusedStream`, only the outer stream does.
+    let mut flat = stream::iter(vec![
+        stream::once(future::ready(1)),
+        stream::once(future::ready(2)),
+    ]).flatten();
+    let lw = noop_waker_ref();
+
+    assert!(!flat.is_terminated());
+    assert_eq!(flat.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert!(!flat.is_terminated());
+    assert_eq!(flat.poll_next_unpin(lw), Poll::Ready(Some(2)));
+    assert!(!flat.is_terminated());
+    assert_eq!(flat.poll_next_unpin(lw), Poll::Ready(None));
+    assert!(flat.is_terminated());
+}
+
+#[test]
+fn metered() {
+    use std::sync::atomic::Ordering;
+
+    let mut stream = stream::iter(vec!['a', 'b', 'c']).interleave_pending().metered();
+    let counter = stream.counter();
+    let lw = noop_waker_ref();
+
+    assert_eq!(counter.load(Ordering::SeqCst), 0);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Pending);
+    assert_eq!(counter.load(Ordering::SeqCst), 0);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some('a')));
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec!['b', 'c']);
+    assert_eq!(counter.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn collect_into_reuses_collection() {
+    let mut buf = Vec::new();
+    for (range, expected) in vec![(1..=3, vec![1, 2, 3]), (4..=5, vec![4, 5])] {
+        buf.clear();
+        let mut fut = stream::iter(range).interleave_pending().collect_into(&mut buf);
+        let lw = noop_waker_ref();
+        loop {
+            assert!(!fut.is_terminated());
+            if fut.poll_unpin(lw) == Poll::Ready(()) {
+                break;
+            }
+        }
+        assert!(fut.is_terminated());
+        drop(fut);
+        assert_eq!(buf, expected);
+    }
+    assert!(buf.capacity() >= 3);
+}
+
+#[test]
+fn from_fn_countdown() {
+    let mut count = 3;
+    let mut calls = 0;
+    let mut countdown = stream::from_fn(|| {
+        calls += 1;
+        if count == 0 {
+            None
+        } else {
+            count -= 1;
+            Some(count)
+        }
+    });
+    let lw = noop_waker_ref();
+
+    for expected in (0..3).rev() {
+        assert!(!countdown.is_terminated());
+        assert_eq!(countdown.poll_next_unpin(lw), Poll::Ready(Some(expected)));
+    }
+    assert_eq!(countdown.poll_next_unpin(lw), Poll::Ready(None));
+    assert!(countdown.is_terminated());
+    assert_eq!(countdown.poll_next_unpin(lw), Poll::Ready(None));
+    drop(countdown);
+    assert_eq!(calls, 4);
+}
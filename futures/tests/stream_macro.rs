@@ -0,0 +1,54 @@
+#![feature(async_await, await_macro, futures_api)]
+
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::stream;
+use futures::stream::{FusedStream, Stream, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_waker_ref;
+use pin_utils::pin_mut;
+
+#[test]
+fn yields_values_then_ends() {
+    let s = stream! {
+        yield_item!(1);
+        yield_item!(2);
+        yield_item!(3);
+    };
+    assert_eq!(block_on(s.collect::<Vec<_>>()), vec![1, 2, 3]);
+}
+
+#[test]
+fn empty_body() {
+    let s = stream! {
+        if false {
+            yield_item!(0u8);
+        }
+    };
+    assert_eq!(block_on(s.collect::<Vec<_>>()), vec![]);
+}
+
+#[test]
+fn awaits_between_items() {
+    let (tx, mut rx) = mpsc::unbounded::<i32>();
+    let s = stream! {
+        while let Some(x) = await!(rx.next()) {
+            yield_item!(x * 2);
+        }
+    };
+    pin_mut!(s);
+    let lw = noop_waker_ref();
+
+    assert_eq!(s.as_mut().poll_next(lw), Poll::Pending);
+    tx.unbounded_send(1).unwrap();
+    tx.unbounded_send(2).unwrap();
+    assert_eq!(s.as_mut().poll_next(lw), Poll::Ready(Some(2)));
+    assert_eq!(s.as_mut().poll_next(lw), Poll::Ready(Some(4)));
+    assert_eq!(s.as_mut().poll_next(lw), Poll::Pending);
+    assert!(!s.is_terminated());
+
+    drop(tx);
+    assert_eq!(s.as_mut().poll_next(lw), Poll::Ready(None));
+    assert!(s.is_terminated());
+    assert_eq!(s.as_mut().poll_next(lw), Poll::Ready(None));
+}
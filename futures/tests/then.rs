@@ -0,0 +1,56 @@
+#![feature(futures_api)]
+
+use futures::future::{self, FusedFuture, Future, FutureExt, TryFutureExt};
+use futures::task::Poll;
+use futures_test::future::FutureTestExt;
+use futures_test::task::noop_waker_ref;
+use pin_utils::pin_mut;
+
+#[test]
+fn then_pins_second_future() {
+    let lw = noop_waker_ref();
+    let fut = future::ready(1)
+        .then(|x| future::ready(x + 1).pending_once().assert_unmoved());
+    pin_mut!(fut);
+
+    assert!(!fut.is_terminated());
+    // The first future completes and the second one is started in place.
+    assert_eq!(fut.as_mut().poll(lw), Poll::Pending);
+    assert!(!fut.is_terminated());
+    assert_eq!(fut.as_mut().poll(lw), Poll::Ready(2));
+    assert!(fut.is_terminated());
+}
+
+#[test]
+fn and_then_pins_second_future() {
+    let lw = noop_waker_ref();
+    let fut = future::ok::<i32, ()>(1)
+        .and_then(|x| future::ok(x + 1).pending_once().assert_unmoved());
+    pin_mut!(fut);
+
+    assert!(!fut.is_terminated());
+    assert_eq!(fut.as_mut().poll(lw), Poll::Pending);
+    assert!(!fut.is_terminated());
+    assert_eq!(fut.as_mut().poll(lw), Poll::Ready(Ok(2)));
+    assert!(fut.is_terminated());
+}
+
+#[test]
+fn and_then_terminates_on_first_error() {
+    let lw = noop_waker_ref();
+    let mut fut = future::err::<i32, i32>(1).and_then(|x| future::ok(x + 1));
+
+    assert!(!fut.is_terminated());
+    assert_eq!(fut.poll_unpin(lw), Poll::Ready(Err(1)));
+    assert!(fut.is_terminated());
+}
+
+#[test]
+fn or_else_terminates_after_second_future() {
+    let lw = noop_waker_ref();
+    let mut fut = future::err::<i32, i32>(1).or_else(|e| future::ok::<i32, i32>(e + 1));
+
+    assert!(!fut.is_terminated());
+    assert_eq!(fut.poll_unpin(lw), Poll::Ready(Ok(2)));
+    assert!(fut.is_terminated());
+}
@@ -0,0 +1,33 @@
+#![feature(futures_api)]
+
+use futures::executor::block_on;
+use futures::stream::{self, TryStreamExt};
+
+#[test]
+fn empty_stream_is_default() {
+    let stream = stream::iter(Vec::<Result<Vec<u8>, ()>>::new());
+    assert_eq!(block_on(stream.try_concat()), Ok(Vec::new()));
+}
+
+#[test]
+fn single_item_is_returned_unchanged() {
+    let item = Vec::<u8>::with_capacity(1024);
+    let ptr = item.as_ptr();
+    let stream = stream::iter(vec![Ok::<_, ()>(item)]);
+
+    let result = block_on(stream.try_concat()).unwrap();
+    assert_eq!(result.as_ptr(), ptr);
+    assert_eq!(result.capacity(), 1024);
+}
+
+#[test]
+fn items_are_concatenated() {
+    let stream = stream::iter(vec![Ok::<_, ()>(vec![1, 2]), Ok(vec![]), Ok(vec![3])]);
+    assert_eq!(block_on(stream.try_concat()), Ok(vec![1, 2, 3]));
+}
+
+#[test]
+fn error_discards_accumulated_items() {
+    let stream = stream::iter(vec![Ok(vec![1, 2]), Err("boom"), Ok(vec![3])]);
+    assert_eq!(block_on(stream.try_concat()), Err("boom"));
+}
@@ -0,0 +1,33 @@
+#![feature(futures_api)]
+
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt, TryStreamExt};
+
+#[test]
+fn filters_and_maps_ok_values() {
+    let stream = stream::iter(vec![Ok::<i32, ()>(1), Ok(2), Ok(3), Ok(4)])
+        .try_filter_map_ok(|x| Ok(if x % 2 == 0 { Some(x * 10) } else { None }));
+
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![Ok(20), Ok(40)]);
+}
+
+#[test]
+fn passes_source_errors_through() {
+    let mut calls = Vec::new();
+    let stream = stream::iter(vec![Ok(1), Err("source"), Ok(2)])
+        .try_filter_map_ok(|x| {
+            calls.push(x);
+            Ok(Some(x))
+        });
+
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![Ok(1), Err("source"), Ok(2)]);
+    assert_eq!(calls, vec![1, 2]);
+}
+
+#[test]
+fn surfaces_closure_errors() {
+    let stream = stream::iter(vec![Ok(1), Ok(-1), Ok(2)])
+        .try_filter_map_ok(|x| if x < 0 { Err("closure") } else { Ok(Some(x)) });
+
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![Ok(1), Err("closure"), Ok(2)]);
+}
@@ -0,0 +1,41 @@
+#![feature(futures_api)]
+
+use futures::future::{self, FusedFuture, FutureExt};
+use futures::stream::{self, TryStreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_waker_ref;
+
+#[test]
+#[should_panic(expected = "TryFold polled after completion")]
+fn poll_after_fold_error() {
+    let lw = noop_waker_ref();
+    let mut fut = stream::iter(vec![Ok::<i32, &str>(1), Ok(2)])
+        .try_fold(0, |acc, x| {
+            future::ready(if x == 2 { Err("boom") } else { Ok(acc + x) })
+        });
+
+    assert_eq!(fut.poll_unpin(lw), Poll::Ready(Err("boom")));
+    assert!(fut.is_terminated());
+    let _ = fut.poll_unpin(lw);
+}
+
+#[test]
+fn stream_error_terminates() {
+    let lw = noop_waker_ref();
+    let mut fut = stream::iter(vec![Ok(1), Err("boom"), Ok(3)])
+        .try_fold(0, |acc, x| future::ready(Ok(acc + x)));
+
+    assert!(!fut.is_terminated());
+    assert_eq!(fut.poll_unpin(lw), Poll::Ready(Err("boom")));
+    assert!(fut.is_terminated());
+}
+
+#[test]
+fn success_terminates() {
+    let lw = noop_waker_ref();
+    let mut fut = stream::iter(vec![Ok::<i32, &str>(1), Ok(2)])
+        .try_fold(0, |acc, x| future::ready(Ok(acc + x)));
+
+    assert_eq!(fut.poll_unpin(lw), Poll::Ready(Ok(3)));
+    assert!(fut.is_terminated());
+}
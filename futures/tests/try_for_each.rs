@@ -0,0 +1,64 @@
+#![feature(futures_api)]
+
+use futures::executor::block_on;
+use futures::future::{self, FusedFuture, FutureExt};
+use futures::stream::{self, StreamExt, TryStreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_waker_ref;
+
+#[test]
+fn stream_error_skips_closure() {
+    let mut calls = Vec::new();
+    let mut stream = stream::iter(vec![Ok(1), Err("boom"), Ok(3)]);
+
+    {
+        let fut = (&mut stream).try_for_each(|item| {
+            calls.push(item);
+            future::ready(Ok(()))
+        });
+        assert_eq!(block_on(fut), Err("boom"));
+    }
+
+    assert_eq!(calls, vec![1]);
+    // The item following the error must not have been pulled.
+    assert_eq!(block_on(stream.next()), Some(Ok(3)));
+}
+
+#[test]
+fn future_error_stops_pulling() {
+    let mut calls = Vec::new();
+    let mut stream = stream::iter(vec![Ok::<i32, &str>(1), Ok(2)]);
+
+    {
+        let fut = (&mut stream).try_for_each(|item| {
+            calls.push(item);
+            future::ready(Err("boom"))
+        });
+        assert_eq!(block_on(fut), Err("boom"));
+    }
+
+    assert_eq!(calls, vec![1]);
+    assert_eq!(block_on(stream.next()), Some(Ok(2)));
+}
+
+#[test]
+fn is_terminated_after_error() {
+    let lw = noop_waker_ref();
+
+    let mut fut = stream::iter(vec![Err::<i32, &str>("boom")])
+        .try_for_each(|_| future::ready(Ok(())));
+    assert!(!fut.is_terminated());
+    assert_eq!(fut.poll_unpin(lw), Poll::Ready(Err("boom")));
+    assert!(fut.is_terminated());
+
+    let mut fut = stream::iter(vec![Ok::<i32, &str>(1)])
+        .try_for_each(|_| future::ready(Err("boom")));
+    assert!(!fut.is_terminated());
+    assert_eq!(fut.poll_unpin(lw), Poll::Ready(Err("boom")));
+    assert!(fut.is_terminated());
+
+    let mut fut = stream::iter(vec![Ok::<i32, &str>(1)])
+        .try_for_each(|_| future::ready(Ok(())));
+    assert_eq!(fut.poll_unpin(lw), Poll::Ready(Ok(())));
+    assert!(fut.is_terminated());
+}
@@ -0,0 +1,67 @@
+#![feature(futures_api)]
+
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::{self, FusedFuture, FutureExt, TryFutureExt};
+use futures::stream::{self, StreamExt, TryStreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_waker_ref;
+use std::cell::Cell;
+
+fn run_with_limit(limit: Option<usize>) -> usize {
+    let (txs, rxs): (Vec<_>, Vec<_>) = (0..5).map(|_| oneshot::channel::<()>()).unzip();
+    let running = Cell::new(0);
+
+    let mut fut = stream::iter(rxs).map(Ok).try_for_each_concurrent(limit, |rx| {
+        running.set(running.get() + 1);
+        rx
+    });
+    assert_eq!(fut.poll_unpin(noop_waker_ref()), Poll::Pending);
+    let max_running = running.get();
+
+    for tx in txs {
+        tx.send(()).unwrap();
+    }
+    assert_eq!(block_on(fut), Ok(()));
+    max_running
+}
+
+#[test]
+fn zero_limit_is_unbounded() {
+    assert_eq!(run_with_limit(Some(0)), 5);
+    assert_eq!(run_with_limit(None), 5);
+    assert_eq!(run_with_limit(Some(2)), 2);
+}
+
+#[test]
+fn error_drops_futures_and_stops_pulling() {
+    let pulled = Cell::new(0);
+    let (tx, rx) = oneshot::channel::<()>();
+    let (keep_tx, keep_rx) = oneshot::channel::<()>();
+    let mut receivers = vec![keep_rx, rx].into_iter();
+
+    let source = stream::poll_fn(|_| {
+        pulled.set(pulled.get() + 1);
+        match receivers.next() {
+            Some(rx) => Poll::Ready(Some(Ok(rx))),
+            None => Poll::Pending,
+        }
+    });
+    let mut fut = source.try_for_each_concurrent(None, |rx| rx.map_err(|_| "canceled"));
+
+    assert_eq!(fut.poll_unpin(noop_waker_ref()), Poll::Pending);
+    let pulled_before_error = pulled.get();
+
+    // Dropping the sender makes the second future fail.
+    drop(tx);
+    assert_eq!(fut.poll_unpin(noop_waker_ref()), Poll::Ready(Err("canceled")));
+    assert_eq!(pulled.get(), pulled_before_error + 1);
+    assert!(fut.is_terminated());
+
+    // The future which was still running has been dropped.
+    assert!(keep_tx.is_canceled());
+
+    let res = block_on(stream::iter(vec![Ok(1), Err("boom"), Ok(3)])
+        .try_for_each_concurrent(0, |_| future::ready(Ok(()))));
+    assert_eq!(res, Err("boom"));
+}
@@ -45,3 +45,65 @@ fn try_join_all_from_iter() {
         Ok::<_, usize>(vec![1, 2]),
     )
 }
+
+#[test]
+fn try_join_all_drops_in_order_on_error() {
+    use futures::task::{Poll, Waker};
+    use futures_test::task::noop_waker_ref;
+    use std::cell::RefCell;
+    use std::pin::Pin;
+    use std::rc::Rc;
+
+    type Log = Rc<RefCell<Vec<String>>>;
+
+    struct Output(usize, Log);
+
+    impl Drop for Output {
+        fn drop(&mut self) {
+            self.1.borrow_mut().push(format!("output {}", self.0));
+        }
+    }
+
+    enum Outcome { Ok, Err, Pending }
+
+    struct Tracked(usize, Outcome, Log);
+
+    impl Future for Tracked {
+        type Output = Result<Output, usize>;
+
+        fn poll(self: Pin<&mut Self>, _: &Waker) -> Poll<Self::Output> {
+            match self.1 {
+                Outcome::Ok => Poll::Ready(Ok(Output(self.0, self.2.clone()))),
+                Outcome::Err => Poll::Ready(Err(self.0)),
+                Outcome::Pending => Poll::Pending,
+            }
+        }
+    }
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            self.2.borrow_mut().push(format!("future {}", self.0));
+        }
+    }
+
+    let log = Log::default();
+    let mut fut = try_join_all(vec![
+        Tracked(0, Outcome::Ok, log.clone()),
+        Tracked(1, Outcome::Pending, log.clone()),
+        Tracked(2, Outcome::Err, log.clone()),
+        Tracked(3, Outcome::Pending, log.clone()),
+    ]);
+
+    match fut.poll_unpin(noop_waker_ref()) {
+        Poll::Ready(Err(2)) => {}
+        _ => panic!("expected the error of the third future"),
+    }
+
+    // Everything is released before the error is returned, in index order,
+    // including the output of the future that already completed.
+    assert_eq!(*log.borrow(), vec![
+        "future 0", "output 0", "future 1", "future 2", "future 3",
+    ]);
+    drop(fut);
+    assert_eq!(log.borrow().len(), 5);
+}
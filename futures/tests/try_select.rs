@@ -0,0 +1,68 @@
+#![feature(futures_api)]
+
+use futures::executor::block_on;
+use futures::future::{self, try_select, Either};
+use futures_test::future::FutureTestExt;
+
+#[test]
+fn first_error_wins() {
+    let a = future::ready(Ok::<i32, &str>(1)).pending_once();
+    let b = future::ready(Err::<i32, &str>("boom"));
+
+    match block_on(try_select(a, b)) {
+        Either::Right((res, a)) => {
+            assert_eq!(res, Err("boom"));
+            // The loser is handed back and can still be driven to completion.
+            assert_eq!(block_on(a), Ok(1));
+        }
+        Either::Left(_) => panic!("expected the failing future to win"),
+    }
+}
+
+#[test]
+fn first_success_wins() {
+    let a = future::ready(Ok::<i32, &str>(1));
+    let b = future::ready(Err::<i32, &str>("boom"));
+
+    match block_on(try_select(a, b)) {
+        Either::Left((res, b)) => {
+            assert_eq!(res, Ok(1));
+            assert_eq!(block_on(b), Err("boom"));
+        }
+        Either::Right(_) => panic!("expected the first future to win"),
+    }
+}
+
+#[test]
+fn factor_winner_output() {
+    let a = future::ready(Ok::<i32, &str>(1)).pending_once();
+    let b = future::ready(Err::<i32, &str>("boom"));
+    let (res, loser) = block_on(try_select(a, b)).factor_first();
+    assert_eq!(res, Err("boom"));
+    match loser {
+        Either::Right(a) => assert_eq!(block_on(a), Ok(1)),
+        Either::Left(_) => panic!("expected the first future to lose"),
+    }
+
+    let a = future::ready(Ok::<i32, &str>(1));
+    let b = future::ready(Err::<i32, &str>("boom"));
+    let (res, loser) = block_on(try_select(a, b)).factor_first();
+    assert_eq!(res, Ok(1));
+    match loser {
+        Either::Left(b) => assert_eq!(block_on(b), Err("boom")),
+        Either::Right(_) => panic!("expected the second future to lose"),
+    }
+}
+
+#[test]
+fn factor_either_of_pairs() {
+    let left: Either<(u32, &str), (u32, char)> = Either::Left((1, "a"));
+    assert_eq!(left.factor_first(), (1, Either::Left("a")));
+    let right: Either<(u32, &str), (u32, char)> = Either::Right((2, 'b'));
+    assert_eq!(right.factor_first(), (2, Either::Right('b')));
+
+    let left: Either<(&str, u32), (char, u32)> = Either::Left(("a", 1));
+    assert_eq!(left.factor_second(), (Either::Left("a"), 1));
+    let right: Either<(&str, u32), (char, u32)> = Either::Right(('b', 2));
+    assert_eq!(right.factor_second(), (Either::Right('b'), 2));
+}
@@ -0,0 +1,53 @@
+#![feature(futures_api)]
+
+use futures::future::{self, FutureExt};
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::future::FutureTestExt;
+use futures_test::task::noop_waker_ref;
+use std::cell::Cell;
+
+struct Guard<'a>(&'a Cell<usize>);
+
+impl Drop for Guard<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn future_enters_and_exits_on_each_poll() {
+    let entered = Cell::new(0);
+    let exited = Cell::new(0);
+    let lw = noop_waker_ref();
+
+    let mut future = future::ready(1).pending_once().with_context(|| {
+        entered.set(entered.get() + 1);
+        // The previous context must have been exited already.
+        assert_eq!(entered.get(), exited.get() + 1);
+        Guard(&exited)
+    });
+
+    assert_eq!(future.poll_unpin(lw), Poll::Pending);
+    assert_eq!((entered.get(), exited.get()), (1, 1));
+    assert_eq!(future.poll_unpin(lw), Poll::Ready(1));
+    assert_eq!((entered.get(), exited.get()), (2, 2));
+}
+
+#[test]
+fn stream_enters_and_exits_on_each_poll() {
+    let entered = Cell::new(0);
+    let exited = Cell::new(0);
+    let lw = noop_waker_ref();
+
+    let mut stream = stream::iter(vec![1, 2]).with_context(|| {
+        entered.set(entered.get() + 1);
+        Guard(&exited)
+    });
+
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert_eq!((entered.get(), exited.get()), (1, 1));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(2)));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+    assert_eq!((entered.get(), exited.get()), (3, 3));
+}